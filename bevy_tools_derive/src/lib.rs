@@ -0,0 +1,215 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Expr, Fields, Ident, LitStr, parse_macro_input};
+
+/// Derives `bevy_tools::attributes::GameplayAttributes` for a struct of `f64` fields.
+///
+/// Every field becomes one attribute. The attribute name defaults to the field name in
+/// UpperCamelCase (`max_health` -> `"MaxHealth"`) and can be overridden per field:
+///
+/// ```ignore
+/// #[derive(GameplayAttributes, Default)]
+/// struct CombatStats {
+///     #[attribute(clamp(min = 0.0, max = 100.0))]
+///     health: f64,
+///     #[attribute(name = "Combat.MaxHealth")]
+///     max_health: f64,
+/// }
+/// ```
+///
+/// A `CombatStatsAttributeIds` resource is generated alongside, with one public
+/// `AttributeId` field and `get_<field>`/`get_<field>_base` accessors per attribute.
+#[proc_macro_derive(GameplayAttributes, attributes(attribute))]
+pub fn derive_gameplay_attributes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct AttributeField {
+    ident: Ident,
+    name: LitStr,
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let struct_ident = &input.ident;
+    let vis = &input.vis;
+    let ids_ident = format_ident!("{}AttributeIds", struct_ident);
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            struct_ident,
+            "GameplayAttributes can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            struct_ident,
+            "GameplayAttributes requires a struct with named fields",
+        ));
+    };
+
+    let fields = fields
+        .named
+        .iter()
+        .map(parse_attribute_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let field_idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let names: Vec<_> = fields.iter().map(|field| &field.name).collect();
+    let clamps: Vec<_> = fields.iter().map(clamp_tokens).collect();
+    let getters: Vec<_> = fields
+        .iter()
+        .map(|field| format_ident!("get_{}", field.ident))
+        .collect();
+    let base_getters: Vec<_> = fields
+        .iter()
+        .map(|field| format_ident!("get_{}_base", field.ident))
+        .collect();
+
+    Ok(quote! {
+        #[derive(::bevy::prelude::Resource, Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #ids_ident {
+            #(pub #field_idents: ::bevy_tools::attributes::AttributeId,)*
+        }
+
+        impl #ids_ident {
+            #(
+                pub fn #getters(
+                    &self,
+                    attributes: &mut ::bevy_tools::attributes::AttributeSet,
+                ) -> Option<f64> {
+                    attributes.get_current_value(self.#field_idents)
+                }
+
+                pub fn #base_getters(
+                    &self,
+                    attributes: &::bevy_tools::attributes::AttributeSet,
+                ) -> Option<f64> {
+                    attributes.get_base_value(self.#field_idents)
+                }
+            )*
+        }
+
+        impl ::bevy_tools::attributes::GameplayAttributes for #struct_ident {
+            type Ids = #ids_ident;
+
+            fn register_attribute_ids(
+                register: &mut ::bevy_tools::attributes::AttributeIdRegister,
+            ) -> Result<Self::Ids, ::bevy_tools::attributes::AttributeIdError> {
+                Ok(#ids_ident {
+                    #(#field_idents: register.request_or_register_attribute_id(#names)?,)*
+                })
+            }
+
+            fn initialize_attribute_set(
+                &self,
+                ids: &Self::Ids,
+                attributes: &mut ::bevy_tools::attributes::AttributeSet,
+            ) {
+                #(
+                    attributes.initialize_attribute(ids.#field_idents, self.#field_idents, None, #clamps);
+                )*
+            }
+
+            fn from_attribute_set(
+                ids: &Self::Ids,
+                attributes: &mut ::bevy_tools::attributes::AttributeSet,
+            ) -> Self {
+                let defaults = <Self as Default>::default();
+                Self {
+                    #(
+                        #field_idents: attributes
+                            .get_current_value(ids.#field_idents)
+                            .unwrap_or(defaults.#field_idents),
+                    )*
+                }
+            }
+        }
+    })
+}
+
+fn parse_attribute_field(field: &syn::Field) -> syn::Result<AttributeField> {
+    let ident = field
+        .ident
+        .clone()
+        .ok_or_else(|| syn::Error::new_spanned(field, "expected a named field"))?;
+    let mut name = LitStr::new(&upper_camel_case(&ident.to_string()), Span::call_site());
+    let mut min = None;
+    let mut max = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("attribute"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = meta.value()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("clamp") {
+                meta.parse_nested_meta(|bound| {
+                    if bound.path.is_ident("min") {
+                        min = Some(bound.value()?.parse()?);
+                        Ok(())
+                    } else if bound.path.is_ident("max") {
+                        max = Some(bound.value()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(bound.error("expected `min` or `max`"))
+                    }
+                })
+            } else {
+                Err(meta.error("expected `name` or `clamp`"))
+            }
+        })?;
+    }
+
+    Ok(AttributeField {
+        ident,
+        name,
+        min,
+        max,
+    })
+}
+
+fn clamp_tokens(field: &AttributeField) -> TokenStream2 {
+    if field.min.is_none() && field.max.is_none() {
+        return quote!(::bevy_tools::attributes::AttributeClamp::None);
+    }
+
+    let min = optional_tokens(field.min.as_ref());
+    let max = optional_tokens(field.max.as_ref());
+    quote! {
+        ::bevy_tools::attributes::AttributeClamp::Range {
+            min: #min,
+            max: #max,
+        }
+    }
+}
+
+fn optional_tokens(value: Option<&Expr>) -> TokenStream2 {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -1,5 +1,7 @@
 pub mod ability_system;
 pub mod attributes;
+pub mod curves;
+pub mod definitions;
 pub mod gameplay_abilities;
 pub mod gameplay_effects;
 pub mod gameplay_tags;
@@ -8,6 +10,8 @@ pub mod settings;
 
 pub use ability_system::*;
 pub use attributes::*;
+pub use curves::*;
+pub use definitions::*;
 pub use gameplay_abilities::*;
 pub use gameplay_effects::*;
 pub use gameplay_tags::*;
@@ -1,5 +1,7 @@
 mod ability_activation_queue;
 mod ability_system_component;
+mod gameplay_time;
 
 pub use ability_activation_queue::*;
 pub use ability_system_component::*;
+pub use gameplay_time::*;
@@ -7,13 +7,15 @@ use crate::gameplay_abilities::{
 use crate::gameplay_effects::{
     ActiveEffectDurationTicks, ActiveEffectPeriodTicks, ActiveGameplayEffect,
     ActiveGameplayEffectTargetIndex, EffectContext, GameplayEffectApplicationPlan,
+    GrantedAbilityRemovalPolicy,
 };
 use crate::gameplay_tags::{
     GameplayTag, GameplayTagContainer, GameplayTagManager, tag_bits_from_tags_with_manager,
 };
 use crate::randoms::Random;
 use crate::{
-    EffectPayload, apply_gameplay_effect, execute_gameplay_effect_plan, prepare_gameplay_effect,
+    EffectApplicationError, EffectPayload, apply_gameplay_effect, execute_gameplay_effect_plan,
+    prepare_gameplay_effect,
 };
 use bevy::ecs::system::SystemParam;
 use bevy::platform::collections::HashMap;
@@ -44,6 +46,7 @@ pub struct AbilitySystemParams<'w, 's> {
     >,
     pub active_ability_query: Query<'w, 's, (Entity, &'static mut ActiveGameplayAbility)>,
     pub time: Res<'w, Time>,
+    pub fixed_time: Res<'w, Time<Fixed>>,
 }
 
 #[derive(Component, Default)]
@@ -87,6 +90,46 @@ impl AbilitySystemComponent {
         removed
     }
 
+    /// Removes the spec now if it has no running instances, otherwise blocks new activations and
+    /// removes it when the last instance ends. Returns `false` if there is no such spec.
+    pub fn clear_ability_on_end(&mut self, handle: AbilitySpecHandle) -> bool {
+        let Some(spec) = self.find_ability_spec_mut(handle) else {
+            return false;
+        };
+        if spec.get_active_count() > 0 {
+            spec.set_remove_on_end();
+            return true;
+        }
+        self.clear_ability(handle)
+    }
+
+    /// Points every spec granted `old` at `new`, e.g. after the definition was hot reloaded, and
+    /// returns how many were updated. Running instances keep the tasks they started with; the
+    /// tags they block are swapped to the new definition's.
+    pub fn replace_ability(
+        &mut self,
+        old: &Arc<GameplayAbility>,
+        new: &Arc<GameplayAbility>,
+        tag_manager: &Res<GameplayTagManager>,
+    ) -> usize {
+        let mut replaced = 0;
+        for spec in self
+            .abilities
+            .iter_mut()
+            .filter(|spec| Arc::ptr_eq(spec.get_ability(), old))
+        {
+            for _ in 0..spec.get_active_count() {
+                self.blocked_ability_tags
+                    .remove_tags(old.get_tags().get_block_abilities_with_tags(), tag_manager);
+                self.blocked_ability_tags
+                    .add_tags(new.get_tags().get_block_abilities_with_tags(), tag_manager);
+            }
+            spec.set_ability(new.clone());
+            replaced += 1;
+        }
+        replaced
+    }
+
     pub fn get_ability_specs(&self) -> &[GameplayAbilitySpec] {
         &self.abilities
     }
@@ -189,6 +232,9 @@ impl AbilitySystemComponent {
 
         if let Some(spec) = self.find_ability_spec_mut(spec_handle) {
             spec.decrement_active_count();
+            if spec.is_remove_on_end() && spec.get_active_count() == 0 {
+                self.clear_ability(spec_handle);
+            }
         }
 
         self.blocked_ability_tags
@@ -219,6 +265,7 @@ pub enum AbilityActivationError {
     CommitPreparationFailed {
         source: Entity,
         handle: AbilitySpecHandle,
+        error: EffectApplicationError,
     },
     StartFailed {
         source: Entity,
@@ -227,6 +274,7 @@ pub enum AbilityActivationError {
     CommitExecutionFailed {
         source: Entity,
         handle: AbilitySpecHandle,
+        error: EffectApplicationError,
     },
 }
 
@@ -255,9 +303,13 @@ impl fmt::Display for AbilityActivationError {
                 "ability activation failed: source entity {source:?} ability handle {} does not meet activation requirements",
                 handle.get_value()
             ),
-            AbilityActivationError::CommitPreparationFailed { source, handle } => write!(
+            AbilityActivationError::CommitPreparationFailed {
+                source,
+                handle,
+                error,
+            } => write!(
                 f,
-                "ability activation failed: source entity {source:?} ability handle {} could not prepare cost or cooldown",
+                "ability activation failed: source entity {source:?} ability handle {} could not prepare cost or cooldown: {error}",
                 handle.get_value()
             ),
             AbilityActivationError::StartFailed { source, handle } => write!(
@@ -265,9 +317,13 @@ impl fmt::Display for AbilityActivationError {
                 "ability activation failed: source entity {source:?} ability handle {} could not start",
                 handle.get_value()
             ),
-            AbilityActivationError::CommitExecutionFailed { source, handle } => write!(
+            AbilityActivationError::CommitExecutionFailed {
+                source,
+                handle,
+                error,
+            } => write!(
                 f,
-                "ability activation failed: source entity {source:?} ability handle {} could not execute cost or cooldown",
+                "ability activation failed: source entity {source:?} ability handle {} could not execute cost or cooldown: {error}",
                 handle.get_value()
             ),
         }
@@ -300,7 +356,10 @@ pub fn try_activate_ability_by_handle(
                 AbilityActivationError::MissingAbilitySystemComponent { source },
             );
         };
-        let Some(spec) = asc.find_ability_spec(handle) else {
+        let Some(spec) = asc
+            .find_ability_spec(handle)
+            .filter(|spec| !spec.is_remove_on_end())
+        else {
             return ability_activation_failed(AbilityActivationError::AbilityNotFound {
                 source,
                 handle,
@@ -344,13 +403,21 @@ pub fn try_activate_ability_by_handle(
         });
     }
 
-    let Some(commit_plans) =
-        prepare_ability_commit_plans(source, &ability, level, Some(&activation_context), params)
-    else {
-        return ability_activation_failed(AbilityActivationError::CommitPreparationFailed {
-            source,
-            handle,
-        });
+    let commit_plans = match prepare_ability_commit_plans(
+        source,
+        &ability,
+        level,
+        Some(&activation_context),
+        params,
+    ) {
+        Ok(commit_plans) => commit_plans,
+        Err(error) => {
+            return ability_activation_failed(AbilityActivationError::CommitPreparationFailed {
+                source,
+                handle,
+                error,
+            });
+        }
     };
 
     let active_handle = {
@@ -370,7 +437,7 @@ pub fn try_activate_ability_by_handle(
         )
     };
 
-    if !execute_ability_commit_plans(commit_plans, params) {
+    if let Err(error) = execute_ability_commit_plans(commit_plans, params) {
         if let Ok(mut asc) = params.asc_query.get_mut(source) {
             asc.rollback_started_ability(
                 active_handle,
@@ -382,6 +449,7 @@ pub fn try_activate_ability_by_handle(
         return ability_activation_failed(AbilityActivationError::CommitExecutionFailed {
             source,
             handle,
+            error,
         });
     }
 
@@ -511,11 +579,8 @@ pub fn commit_ability(
     ability: &Arc<GameplayAbility>,
     level: u32,
     params: &mut AbilitySystemParams,
-) -> bool {
-    let Some(plans) = prepare_ability_commit_plans(source, ability, level, None, params) else {
-        return false;
-    };
-
+) -> Result<(), EffectApplicationError> {
+    let plans = prepare_ability_commit_plans(source, ability, level, None, params)?;
     execute_ability_commit_plans(plans, params)
 }
 
@@ -530,20 +595,22 @@ fn prepare_ability_commit_plans(
     level: u32,
     activation_context: Option<&AbilityActivationContext>,
     params: &mut AbilitySystemParams,
-) -> Option<AbilityCommitPlans> {
+) -> Result<AbilityCommitPlans, EffectApplicationError> {
     let cost_plan = if let Some(cost_def) = ability.get_cost() {
         if !cost_def.has_only_add_modifiers() {
-            return None;
+            return Err(EffectApplicationError::InvalidSpec {
+                reason: "cost effects may only use Add modifiers",
+            });
         }
         let payload =
             effect_payload_from_optional_activation_context(source, level, activation_context);
         let plan = prepare_gameplay_effect(source, cost_def, params, &payload)?;
         if !plan.is_instant() {
-            return None;
-        }
-        if !can_pay_prepared_cost(source, &plan, params) {
-            return None;
+            return Err(EffectApplicationError::InvalidSpec {
+                reason: "cost effects must be instant",
+            });
         }
+        check_can_pay_prepared_cost(source, &plan, params)?;
         Some(plan)
     } else {
         None
@@ -558,7 +625,7 @@ fn prepare_ability_commit_plans(
         None
     };
 
-    Some(AbilityCommitPlans {
+    Ok(AbilityCommitPlans {
         cost_plan,
         cooldown_plan,
     })
@@ -593,41 +660,40 @@ fn effect_payload_from_activation_context(
 fn execute_ability_commit_plans(
     plans: AbilityCommitPlans,
     params: &mut AbilitySystemParams,
-) -> bool {
-    if let Some(plan) = plans.cost_plan
-        && !execute_gameplay_effect_plan(plan, params)
-    {
-        return false;
+) -> Result<(), EffectApplicationError> {
+    if let Some(plan) = plans.cost_plan {
+        execute_gameplay_effect_plan(plan, params)?;
     }
 
-    if let Some(plan) = plans.cooldown_plan
-        && !execute_gameplay_effect_plan(plan, params)
-    {
-        return false;
+    if let Some(plan) = plans.cooldown_plan {
+        execute_gameplay_effect_plan(plan, params)?;
     }
 
-    true
+    Ok(())
 }
 
-fn can_pay_prepared_cost(
+fn check_can_pay_prepared_cost(
     source: Entity,
     cost_plan: &GameplayEffectApplicationPlan,
     params: &mut AbilitySystemParams,
-) -> bool {
+) -> Result<(), EffectApplicationError> {
     let Ok(mut attr_set) = params.attr_set_query.get_mut(source) else {
-        return false;
+        return Err(EffectApplicationError::MissingAttributeSet { target: source });
     };
 
     for cost in cost_plan.get_modifier_specs() {
-        let Some(current_val) = attr_set.get_current_value(cost.get_id()) else {
-            return false;
-        };
-        if current_val + cost.get_value() < 0.0 {
-            return false;
+        let affordable = attr_set
+            .get_current_value(cost.get_id())
+            .is_some_and(|current_val| current_val + cost.get_value() >= 0.0);
+        if !affordable {
+            return Err(EffectApplicationError::InsufficientAttribute {
+                target: source,
+                attribute: cost.get_id(),
+            });
         }
     }
 
-    true
+    Ok(())
 }
 
 fn finish_ability_with_status(
@@ -711,6 +777,40 @@ fn cancel_active_abilities_with_tags(
     }
 }
 
+/// Clears abilities an active effect granted to `owner` when the effect is removed.
+pub(crate) fn remove_granted_abilities(
+    world: &mut World,
+    owner: Entity,
+    granted_abilities: &[(AbilitySpecHandle, GrantedAbilityRemovalPolicy)],
+) {
+    let cancelled: Vec<AbilitySpecHandle> = granted_abilities
+        .iter()
+        .filter(|(_, policy)| *policy == GrantedAbilityRemovalPolicy::CancelImmediately)
+        .map(|(handle, _)| *handle)
+        .collect();
+    if !cancelled.is_empty() {
+        let mut active_ability_query = world.query::<&mut ActiveGameplayAbility>();
+        for mut active_ability in active_ability_query.iter_mut(world) {
+            if active_ability.get_source() == owner
+                && cancelled.contains(&active_ability.get_spec_handle())
+                && !matches!(
+                    active_ability.get_status(),
+                    AbilityActivationStatus::Ending | AbilityActivationStatus::Cancelled
+                )
+            {
+                active_ability.set_status(AbilityActivationStatus::Cancelled);
+            }
+        }
+    }
+
+    let Some(mut ability_system) = world.get_mut::<AbilitySystemComponent>(owner) else {
+        return;
+    };
+    for (handle, _) in granted_abilities {
+        ability_system.clear_ability_on_end(*handle);
+    }
+}
+
 fn can_pay_ability_cost(
     source: Entity,
     target: Entity,
@@ -730,6 +830,7 @@ fn can_pay_ability_cost(
         let context = EffectContext {
             target: Some(target),
             payload: &payload,
+            fixed_timestep: params.fixed_time.timestep(),
             attr_set_query: &params.attr_set_query.as_readonly(),
             tag_container_query: &params.tag_container_query.as_readonly(),
             asc_query: &params.asc_query.as_readonly(),
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+/// Global switch for everything that advances with time: effect durations and periods,
+/// attribute regeneration, ability tasks and [`GameplayTimeScale`] accumulation.
+///
+/// Queues, tag requirements and attribute recalculation keep running while paused.
+#[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GameplayAbilitySystemState {
+    #[default]
+    Running,
+    Paused,
+}
+
+/// Per-entity time dilation for the effects applied to it, its regeneration and the tasks of
+/// the abilities it activated. Entities without it advance exactly one tick per fixed tick.
+///
+/// Fractional scales accumulate across fixed ticks, e.g. `0.5` advances one tick every other
+/// fixed tick and `2.0` advances two ticks every fixed tick.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct GameplayTimeScale {
+    scale: f64,
+    paused: bool,
+    accumulated: f64,
+    pending_ticks: u32,
+}
+
+impl Default for GameplayTimeScale {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl GameplayTimeScale {
+    /// Accumulated ticks within this distance of a whole tick count as that tick.
+    const WHOLE_TICK_TOLERANCE: f64 = 1e-6;
+
+    /// Negative and non-finite scales are treated as `0.0`.
+    pub fn new(scale: f64) -> Self {
+        Self {
+            scale: sanitize_scale(scale),
+            paused: false,
+            accumulated: 0.0,
+            pending_ticks: 0,
+        }
+    }
+
+    /// Time stop: nothing advances until unpaused, and the accumulated fraction is kept.
+    pub fn paused() -> Self {
+        Self {
+            paused: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn get_scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = sanitize_scale(scale);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Fraction of a tick carried over to the next fixed tick.
+    pub fn get_accumulated(&self) -> f64 {
+        self.accumulated
+    }
+
+    /// Ticks the owner advances during the current fixed tick.
+    pub fn get_pending_ticks(&self) -> u32 {
+        self.pending_ticks
+    }
+
+    fn advance(&mut self) {
+        if self.paused || self.scale == 0.0 {
+            self.pending_ticks = 0;
+            return;
+        }
+
+        self.accumulated += self.scale;
+        let whole_ticks = (self.accumulated + Self::WHOLE_TICK_TOLERANCE).floor();
+        self.accumulated = (self.accumulated - whole_ticks).max(0.0);
+        self.pending_ticks = whole_ticks.min(f64::from(u32::MAX)) as u32;
+    }
+}
+
+fn sanitize_scale(scale: f64) -> f64 {
+    if scale.is_finite() {
+        scale.max(0.0)
+    } else {
+        0.0
+    }
+}
+
+/// Ticks `entity` advances this fixed tick, `1` when it has no [`GameplayTimeScale`].
+pub fn get_gameplay_time_ticks(time_query: &Query<&GameplayTimeScale>, entity: Entity) -> u32 {
+    time_query
+        .get(entity)
+        .map_or(1, GameplayTimeScale::get_pending_ticks)
+}
+
+pub fn advance_gameplay_time_scales_system(mut query: Query<&mut GameplayTimeScale>) {
+    for mut time_scale in query.iter_mut() {
+        time_scale.advance();
+    }
+}
@@ -1,13 +1,25 @@
 mod attribute;
 mod attribute_aggregator;
+mod attribute_breakdown;
+mod attribute_history;
 mod attribute_id_manager;
+mod attribute_math;
+mod attribute_regeneration;
 mod attribute_set;
 mod attribute_set_snapshot;
 mod attribute_snapshot;
+mod attribute_threshold;
+mod gameplay_attributes;
 
 pub use attribute::*;
 pub use attribute_aggregator::*;
+pub use attribute_breakdown::*;
+pub use attribute_history::*;
 pub use attribute_id_manager::*;
+pub use attribute_math::FixedPoint;
+pub use attribute_regeneration::*;
 pub use attribute_set::*;
 pub use attribute_set_snapshot::*;
 pub use attribute_snapshot::*;
+pub use attribute_threshold::*;
+pub use gameplay_attributes::*;
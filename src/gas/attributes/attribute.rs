@@ -1,4 +1,8 @@
 use super::attribute_aggregator::Aggregator;
+use super::attribute_breakdown::{AttributeBreakdown, AttributeModifierContribution};
+use super::attribute_history::AttributeChangeCause;
+use super::attribute_id_manager::AttributeId;
+use super::attribute_math::{add_values, mul_values, quantize};
 use super::attribute_snapshot::AttributeSnapshot;
 use crate::gameplay_effects::ActiveEffectHandle;
 use crate::modifiers::{ModifierOperation, ModifierSpec};
@@ -21,6 +25,8 @@ pub struct Attribute {
     aggregator: Aggregator,
     dirty: bool,
     clamp: AttributeClamp,
+    last_change_cause: Option<AttributeChangeCause>,
+    pending_change_cause: Option<AttributeChangeCause>,
 }
 
 impl Default for Attribute {
@@ -32,6 +38,8 @@ impl Default for Attribute {
             aggregator: Aggregator::default(),
             dirty: true,
             clamp: AttributeClamp::None,
+            last_change_cause: None,
+            pending_change_cause: None,
         }
     }
 }
@@ -43,18 +51,24 @@ impl Attribute {
         executor: Option<fn(&Aggregator, f64) -> f64>,
         clamp: AttributeClamp,
     ) {
-        self.base = base_value;
+        self.base = quantize(base_value);
         self.clamp = clamp;
         self.set_executor(executor);
         self.recalculate();
     }
 
     pub fn recalculate(&mut self) {
+        let previous_current = self.current;
+        let was_dirty = self.dirty;
         if self.dirty {
-            self.evaluated = self.aggregator.evaluate(self.base);
+            self.evaluated = quantize(self.aggregator.evaluate(self.base));
             self.dirty = false;
         }
         self.clamp_current();
+        // A change made without a cause, e.g. a clamp change, must not inherit the last one.
+        if was_dirty && (self.pending_change_cause.is_some() || self.current != previous_current) {
+            self.last_change_cause = self.pending_change_cause.take();
+        }
     }
 
     pub fn get_current_value(&mut self) -> f64 {
@@ -62,6 +76,16 @@ impl Attribute {
         self.current
     }
 
+    /// Current value without touching the cache; re-evaluates the aggregator if dirty.
+    pub fn calculate_current_value(&self) -> f64 {
+        let evaluated = if self.dirty {
+            quantize(self.aggregator.evaluate(self.base))
+        } else {
+            self.evaluated
+        };
+        self.clamp_value(evaluated)
+    }
+
     pub fn get_base_value(&self) -> f64 {
         self.base
     }
@@ -70,21 +94,43 @@ impl Attribute {
         self.clamp
     }
 
+    /// Cause of the changes since the last recalculation, or of the changes that recalculation
+    /// applied; `None` when they were made without a cause.
+    pub fn get_last_change_cause(&self) -> Option<AttributeChangeCause> {
+        if !self.dirty || (self.pending_change_cause.is_none() && !self.has_pending_change()) {
+            self.last_change_cause
+        } else {
+            self.pending_change_cause
+        }
+    }
+
+    fn has_pending_change(&self) -> bool {
+        self.calculate_current_value() != self.current
+    }
+
+    /// Sets the cause of the pending change; it is reset by the next recalculation.
+    pub fn set_last_change_cause(&mut self, cause: Option<AttributeChangeCause>) {
+        self.pending_change_cause = cause;
+    }
+
     pub fn set_clamp(&mut self, clamp: AttributeClamp) {
         self.clamp = clamp;
         self.make_dirty();
     }
 
     fn clamp_current(&mut self) {
+        self.current = self.clamp_value(self.evaluated);
+    }
+
+    fn clamp_value(&self, mut value: f64) -> f64 {
         let (min, max) = self.get_clamp_bounds();
-        let mut value = self.evaluated;
         if let Some(min) = min {
             value = value.max(min);
         }
         if let Some(max) = max {
             value = value.min(max);
         }
-        self.current = value;
+        quantize(value)
     }
 
     #[inline]
@@ -108,12 +154,13 @@ impl Attribute {
     }
 
     pub fn modify_base_value(&mut self, spec: &ModifierSpec) {
-        match spec.get_operation() {
-            ModifierOperation::Add => self.base += spec.get_value(),
-            ModifierOperation::PercentAdd => self.base *= 1.0 + spec.get_value(),
-            ModifierOperation::Multiply => self.base *= spec.get_value(),
-            ModifierOperation::Override => self.base = spec.get_value(),
-        }
+        let value = spec.get_value();
+        self.base = match spec.get_operation() {
+            ModifierOperation::Add => add_values(self.base, value),
+            ModifierOperation::PercentAdd => mul_values(self.base, add_values(1.0, value)),
+            ModifierOperation::Multiply => mul_values(self.base, value),
+            ModifierOperation::Override => quantize(value),
+        };
         self.make_dirty();
     }
 
@@ -127,6 +174,46 @@ impl Attribute {
         self.aggregator.modifier_count()
     }
 
+    pub fn make_breakdown(&mut self, id: AttributeId) -> AttributeBreakdown {
+        self.recalculate();
+        let modifiers = [
+            ModifierOperation::Add,
+            ModifierOperation::PercentAdd,
+            ModifierOperation::Multiply,
+        ]
+        .into_iter()
+        .flat_map(|operation| {
+            self.aggregator
+                .get_modifiers(operation)
+                .iter()
+                .map(move |modifier| {
+                    AttributeModifierContribution::new(
+                        operation,
+                        modifier.get_value(),
+                        modifier.get_handle(),
+                    )
+                })
+        })
+        .collect();
+        let override_modifier = self.aggregator.get_override().map(|modifier| {
+            AttributeModifierContribution::new(
+                ModifierOperation::Override,
+                modifier.get_value(),
+                modifier.get_handle(),
+            )
+        });
+
+        AttributeBreakdown::new(
+            id,
+            self.base,
+            modifiers,
+            override_modifier,
+            self.evaluated,
+            self.clamp,
+            self.current,
+        )
+    }
+
     pub fn make_snapshot(&self) -> AttributeSnapshot {
         AttributeSnapshot::new(self.base, self.current)
     }
@@ -1,3 +1,4 @@
+use super::attribute_math::{add_values, mul_values, quantize};
 use crate::gameplay_effects::ActiveEffectHandle;
 use crate::modifiers::{AppliedModifier, ModifierOperation, ModifierSpec};
 
@@ -7,16 +8,16 @@ pub fn default_executor(aggregator: &Aggregator, base_value: f64) -> f64 {
     }
     let mut final_value = base_value;
     for &add in &aggregator.additive {
-        final_value += add.get_value();
+        final_value = add_values(final_value, add.get_value());
     }
     let mut percent_sum = 0.0;
     for &percent in &aggregator.percent_additive {
-        percent_sum += percent.get_value();
+        percent_sum = add_values(percent_sum, percent.get_value());
     }
-    final_value *= 1.0 + percent_sum;
+    final_value = mul_values(final_value, add_values(1.0, percent_sum));
 
     for &multiplier in &aggregator.multiplicative {
-        final_value *= multiplier.get_value();
+        final_value = mul_values(final_value, multiplier.get_value());
     }
 
     final_value
@@ -51,7 +52,7 @@ impl Aggregator {
     }
 
     pub fn apply_modifier_spec(&mut self, spec: &ModifierSpec, handle: ActiveEffectHandle) {
-        let applied_modifier = AppliedModifier::new(handle, spec.get_value());
+        let applied_modifier = AppliedModifier::new(handle, quantize(spec.get_value()));
         match spec.get_operation() {
             ModifierOperation::Add => self.additive.push(applied_modifier),
             ModifierOperation::Multiply => self.multiplicative.push(applied_modifier),
@@ -81,6 +82,19 @@ impl Aggregator {
         self.override_value = None;
     }
 
+    pub fn get_modifiers(&self, operation: ModifierOperation) -> &[AppliedModifier] {
+        match operation {
+            ModifierOperation::Add => &self.additive,
+            ModifierOperation::PercentAdd => &self.percent_additive,
+            ModifierOperation::Multiply => &self.multiplicative,
+            ModifierOperation::Override => self.override_value.as_slice(),
+        }
+    }
+
+    pub fn get_override(&self) -> Option<AppliedModifier> {
+        self.override_value
+    }
+
     /// Returns the total number of modifiers across all operation slots.
     pub fn modifier_count(&self) -> usize {
         self.additive.len()
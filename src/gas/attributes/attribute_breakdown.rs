@@ -0,0 +1,138 @@
+use super::{AttributeClamp, AttributeId};
+use crate::gameplay_effects::ActiveEffectHandle;
+use crate::gameplay_tags::GameplayTag;
+use crate::modifiers::ModifierOperation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifierContribution {
+    operation: ModifierOperation,
+    value: f64,
+    source_effect: ActiveEffectHandle,
+    source_asset_tags: Vec<GameplayTag>,
+}
+
+impl AttributeModifierContribution {
+    pub fn new(
+        operation: ModifierOperation,
+        value: f64,
+        source_effect: ActiveEffectHandle,
+    ) -> Self {
+        Self {
+            operation,
+            value,
+            source_effect,
+            source_asset_tags: Vec::new(),
+        }
+    }
+
+    pub fn get_operation(&self) -> ModifierOperation {
+        self.operation
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn get_source_effect(&self) -> ActiveEffectHandle {
+        self.source_effect
+    }
+
+    /// Asset tags of the source effect; empty until resolved against the active effects.
+    pub fn get_source_asset_tags(&self) -> &[GameplayTag] {
+        &self.source_asset_tags
+    }
+
+    pub fn set_source_asset_tags(&mut self, source_asset_tags: Vec<GameplayTag>) {
+        self.source_asset_tags = source_asset_tags;
+    }
+}
+
+/// Explains how an attribute's current value was produced from its base value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeBreakdown {
+    id: AttributeId,
+    base_value: f64,
+    modifiers: Vec<AttributeModifierContribution>,
+    override_modifier: Option<AttributeModifierContribution>,
+    evaluated_value: f64,
+    clamp: AttributeClamp,
+    current_value: f64,
+}
+
+impl AttributeBreakdown {
+    pub fn new(
+        id: AttributeId,
+        base_value: f64,
+        modifiers: Vec<AttributeModifierContribution>,
+        override_modifier: Option<AttributeModifierContribution>,
+        evaluated_value: f64,
+        clamp: AttributeClamp,
+        current_value: f64,
+    ) -> Self {
+        Self {
+            id,
+            base_value,
+            modifiers,
+            override_modifier,
+            evaluated_value,
+            clamp,
+            current_value,
+        }
+    }
+
+    pub fn get_id(&self) -> AttributeId {
+        self.id
+    }
+
+    pub fn get_base_value(&self) -> f64 {
+        self.base_value
+    }
+
+    /// Add, PercentAdd and Multiply contributions, in evaluation order.
+    pub fn get_modifiers(&self) -> &[AttributeModifierContribution] {
+        &self.modifiers
+    }
+
+    pub fn get_override(&self) -> Option<&AttributeModifierContribution> {
+        self.override_modifier.as_ref()
+    }
+
+    /// Value after modifier aggregation, before the clamp is applied.
+    pub fn get_evaluated_value(&self) -> f64 {
+        self.evaluated_value
+    }
+
+    pub fn get_clamp(&self) -> AttributeClamp {
+        self.clamp
+    }
+
+    pub fn get_current_value(&self) -> f64 {
+        self.current_value
+    }
+
+    pub fn contributions_from(
+        &self,
+        source_effect: ActiveEffectHandle,
+    ) -> impl Iterator<Item = &AttributeModifierContribution> + '_ {
+        self.modifiers
+            .iter()
+            .chain(self.override_modifier.as_ref())
+            .filter(move |contribution| contribution.get_source_effect() == source_effect)
+    }
+
+    /// Fills in the asset tags of every contribution's source effect.
+    pub fn resolve_source_asset_tags(
+        &mut self,
+        mut asset_tags_for: impl FnMut(ActiveEffectHandle) -> Option<Vec<GameplayTag>>,
+    ) {
+        for contribution in self
+            .modifiers
+            .iter_mut()
+            .chain(self.override_modifier.as_mut())
+        {
+            if let Some(asset_tags) = asset_tags_for(contribution.get_source_effect()) {
+                contribution.set_source_asset_tags(asset_tags);
+            }
+        }
+    }
+}
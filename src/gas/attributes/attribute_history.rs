@@ -0,0 +1,188 @@
+use super::{AttributeId, AttributeSet};
+use crate::gameplay_effects::ActiveEffectHandle;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+
+/// What last changed an attribute, as recorded by [`AttributeSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeChangeCause {
+    /// A duration modifier was added or removed, or a periodic effect executed.
+    ActiveEffect(ActiveEffectHandle),
+    /// An instant effect, which has no active effect entity.
+    InstantEffect {
+        source: Entity,
+    },
+    Regeneration,
+}
+
+impl fmt::Display for AttributeChangeCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeChangeCause::ActiveEffect(handle) => write!(f, "active_effect:{handle}"),
+            AttributeChangeCause::InstantEffect { source } => write!(f, "instant_effect:{source}"),
+            AttributeChangeCause::Regeneration => write!(f, "regeneration"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeHistorySample {
+    tick: u64,
+    base_value: f64,
+    current_value: f64,
+    cause: Option<AttributeChangeCause>,
+}
+
+impl AttributeHistorySample {
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn get_base_value(&self) -> f64 {
+        self.base_value
+    }
+
+    pub fn get_current_value(&self) -> f64 {
+        self.current_value
+    }
+
+    /// Set only on samples whose value differs from the previous sample.
+    pub fn get_cause(&self) -> Option<AttributeChangeCause> {
+        self.cause
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AttributeHistoryTrack {
+    id: AttributeId,
+    samples: VecDeque<AttributeHistorySample>,
+}
+
+/// Global fixed-tick counter that stamps [`AttributeHistory`] samples, so series recorded on
+/// different entities, including entities added later, line up.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AttributeHistoryClock {
+    tick: u64,
+}
+
+impl AttributeHistoryClock {
+    /// Number of fixed ticks recorded so far; the next samples are stamped with this tick.
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+}
+
+/// Opt-in recorder that keeps the last `capacity` fixed-tick samples of selected attributes.
+#[derive(Component, Debug, Clone)]
+pub struct AttributeHistory {
+    tracks: Vec<AttributeHistoryTrack>,
+    capacity: usize,
+}
+
+impl AttributeHistory {
+    pub fn new(attributes: impl IntoIterator<Item = AttributeId>, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            tracks: attributes
+                .into_iter()
+                .map(|id| AttributeHistoryTrack {
+                    id,
+                    samples: VecDeque::with_capacity(capacity),
+                })
+                .collect(),
+            capacity,
+        }
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn get_attributes(&self) -> impl Iterator<Item = AttributeId> + '_ {
+        self.tracks.iter().map(|track| track.id)
+    }
+
+    /// Samples of `id` from oldest to newest, or `None` if `id` is not recorded.
+    pub fn get_samples(
+        &self,
+        id: AttributeId,
+    ) -> Option<impl Iterator<Item = &AttributeHistorySample> + '_> {
+        self.tracks
+            .iter()
+            .find(|track| track.id == id)
+            .map(|track| track.samples.iter())
+    }
+
+    pub fn clear(&mut self) {
+        for track in &mut self.tracks {
+            track.samples.clear();
+        }
+    }
+
+    /// Appends one sample per recorded attribute, stamped with `tick`; attributes missing from
+    /// `attr_set` are skipped.
+    pub fn record(&mut self, attr_set: &AttributeSet, tick: u64) {
+        for track in &mut self.tracks {
+            let (Some(base_value), Some(current_value)) = (
+                attr_set.get_base_value(track.id),
+                attr_set.calculate_current_value(track.id),
+            ) else {
+                continue;
+            };
+            let changed = track.samples.back().is_none_or(|last| {
+                last.base_value != base_value || last.current_value != current_value
+            });
+            let cause = if changed {
+                attr_set.get_last_change_cause(track.id)
+            } else {
+                None
+            };
+
+            if track.samples.len() == self.capacity {
+                track.samples.pop_front();
+            }
+            track.samples.push_back(AttributeHistorySample {
+                tick,
+                base_value,
+                current_value,
+                cause,
+            });
+        }
+    }
+
+    /// `tick,attribute,base,current,cause` rows, with attributes written as their index.
+    pub fn to_csv(&self) -> String {
+        self.to_csv_with_labels(|id| id.to_index().to_string())
+    }
+
+    pub fn to_csv_with_labels(&self, mut label: impl FnMut(AttributeId) -> String) -> String {
+        let mut csv = String::from("tick,attribute,base,current,cause\n");
+        for track in &self.tracks {
+            let attribute = label(track.id);
+            for sample in &track.samples {
+                let cause = sample
+                    .cause
+                    .map(|cause| cause.to_string())
+                    .unwrap_or_default();
+                // Writing into a `String` cannot fail.
+                let _ = writeln!(
+                    csv,
+                    "{},{attribute},{},{},{cause}",
+                    sample.tick, sample.base_value, sample.current_value
+                );
+            }
+        }
+        csv
+    }
+}
+
+pub fn record_attribute_history_system(
+    mut clock: ResMut<AttributeHistoryClock>,
+    mut query: Query<(&mut AttributeHistory, &AttributeSet)>,
+) {
+    for (mut history, attr_set) in query.iter_mut() {
+        history.record(attr_set, clock.tick);
+    }
+    clock.tick += 1;
+}
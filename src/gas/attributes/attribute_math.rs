@@ -0,0 +1,109 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Signed Q43.20 fixed-point number used for attribute math when the `fixed-point` feature is on.
+///
+/// Values round-trip through `f64` exactly while `|value| < 2^33`, so attributes keep their `f64`
+/// API while every aggregator step runs on integers. Conversions and multiplications round half
+/// away from zero; overflow saturates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FixedPoint(i64);
+
+impl FixedPoint {
+    pub const FRACTIONAL_BITS: u32 = 20;
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRACTIONAL_BITS);
+
+    const SCALE: f64 = (1u64 << Self::FRACTIONAL_BITS) as f64;
+
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Non-finite inputs saturate (`NaN` becomes zero).
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * Self::SCALE).round() as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::SCALE
+    }
+}
+
+impl Add for FixedPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for FixedPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for FixedPoint {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let product = i128::from(self.0) * i128::from(rhs.0);
+        let half = 1i128 << (Self::FRACTIONAL_BITS - 1);
+        let rounded = if product >= 0 {
+            (product + half) >> Self::FRACTIONAL_BITS
+        } else {
+            -((-product + half) >> Self::FRACTIONAL_BITS)
+        };
+        Self(rounded.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64)
+    }
+}
+
+impl Neg for FixedPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+// Attribute storage stays `f64`; with `fixed-point` every stored value sits on the fixed grid and
+// arithmetic goes through `FixedPoint`, otherwise these are plain float operations.
+
+#[cfg(feature = "fixed-point")]
+pub(crate) fn quantize(value: f64) -> f64 {
+    FixedPoint::from_f64(value).to_f64()
+}
+
+#[cfg(not(feature = "fixed-point"))]
+#[inline]
+pub(crate) fn quantize(value: f64) -> f64 {
+    value
+}
+
+#[cfg(feature = "fixed-point")]
+pub(crate) fn add_values(a: f64, b: f64) -> f64 {
+    (FixedPoint::from_f64(a) + FixedPoint::from_f64(b)).to_f64()
+}
+
+#[cfg(not(feature = "fixed-point"))]
+#[inline]
+pub(crate) fn add_values(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[cfg(feature = "fixed-point")]
+pub(crate) fn mul_values(a: f64, b: f64) -> f64 {
+    (FixedPoint::from_f64(a) * FixedPoint::from_f64(b)).to_f64()
+}
+
+#[cfg(not(feature = "fixed-point"))]
+#[inline]
+pub(crate) fn mul_values(a: f64, b: f64) -> f64 {
+    a * b
+}
@@ -0,0 +1,168 @@
+use super::{AttributeChangeCause, AttributeId, AttributeSet};
+use crate::ability_system::GameplayTimeScale;
+use crate::gameplay_effects::TagRequirements;
+use crate::gameplay_tags::GameplayTagContainer;
+use crate::modifiers::{ModifierOperation, ModifierSpec};
+use bevy::prelude::*;
+
+/// Adds the current value of `rate` to the base value of `target` every effect tick,
+/// scaled by the owner's [`GameplayTimeScale`].
+pub struct Regeneration {
+    target: AttributeId,
+    rate: AttributeId,
+    cap: Option<AttributeId>,
+    delay_after_decrease_ticks: u32,
+    tag_requirements: TagRequirements,
+    remain_delay_ticks: u32,
+    last_value: Option<f64>,
+}
+
+impl Regeneration {
+    pub fn new(target: AttributeId, rate: AttributeId) -> Self {
+        Self {
+            target,
+            rate,
+            cap: None,
+            delay_after_decrease_ticks: 0,
+            tag_requirements: TagRequirements::default(),
+            remain_delay_ticks: 0,
+            last_value: None,
+        }
+    }
+
+    /// Stops regenerating once `target` reaches the current value of `cap`.
+    pub fn with_cap(mut self, cap: AttributeId) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Pauses regeneration for `ticks` ticks whenever `target` dropped since the previous tick.
+    pub fn with_delay_after_decrease(mut self, ticks: u32) -> Self {
+        self.delay_after_decrease_ticks = ticks;
+        self
+    }
+
+    /// Only regenerates while the owner's tags pass, e.g. ignore `State.NoRegen`.
+    /// Owners without a `GameplayTagContainer` only pass when no tags are required.
+    pub fn with_tag_requirements(mut self, tag_requirements: TagRequirements) -> Self {
+        self.tag_requirements = tag_requirements;
+        self
+    }
+
+    pub fn get_target(&self) -> AttributeId {
+        self.target
+    }
+
+    pub fn get_rate(&self) -> AttributeId {
+        self.rate
+    }
+
+    pub fn get_cap(&self) -> Option<AttributeId> {
+        self.cap
+    }
+
+    pub fn get_delay_after_decrease_ticks(&self) -> u32 {
+        self.delay_after_decrease_ticks
+    }
+
+    pub fn get_tag_requirements(&self) -> &TagRequirements {
+        &self.tag_requirements
+    }
+
+    pub fn get_remain_delay_ticks(&self) -> u32 {
+        self.remain_delay_ticks
+    }
+
+    fn passes_tag_requirements(&self, tags: Option<&GameplayTagContainer>) -> bool {
+        match tags {
+            Some(tags) => self.tag_requirements.passes(Some(tags)),
+            None => self.tag_requirements.get_required_tags().is_empty(),
+        }
+    }
+
+    fn tick(&mut self, attr_set: &mut AttributeSet, tags: Option<&GameplayTagContainer>) {
+        let Some(current) = attr_set.get_current_value(self.target) else {
+            return;
+        };
+        if self
+            .last_value
+            .is_some_and(|last_value| current < last_value)
+        {
+            self.remain_delay_ticks = self.delay_after_decrease_ticks;
+        }
+        self.last_value = Some(current);
+
+        if self.remain_delay_ticks > 0 {
+            self.remain_delay_ticks -= 1;
+            return;
+        }
+        if !self.passes_tag_requirements(tags) {
+            return;
+        }
+
+        let Some(mut amount) = attr_set.get_current_value(self.rate) else {
+            return;
+        };
+        if amount > 0.0
+            && let Some(cap) = self.cap.and_then(|cap| attr_set.get_current_value(cap))
+        {
+            amount = amount.min(cap - current).max(0.0);
+        }
+        if amount == 0.0 {
+            return;
+        }
+
+        attr_set.set_change_cause(Some(AttributeChangeCause::Regeneration));
+        attr_set.apply_instant_modifier(&ModifierSpec::new(
+            self.target,
+            ModifierOperation::Add,
+            amount,
+        ));
+        attr_set.set_change_cause(None);
+        self.last_value = attr_set.get_current_value(self.target);
+    }
+}
+
+/// Regeneration entries of one entity, usually one per resource (health, mana, ...).
+#[derive(Component, Default)]
+pub struct AttributeRegeneration {
+    regenerations: Vec<Regeneration>,
+}
+
+impl AttributeRegeneration {
+    pub fn new(regenerations: Vec<Regeneration>) -> Self {
+        Self { regenerations }
+    }
+
+    pub fn add_regeneration(&mut self, regeneration: Regeneration) {
+        self.regenerations.push(regeneration);
+    }
+
+    pub fn get_regenerations(&self) -> &[Regeneration] {
+        &self.regenerations
+    }
+
+    pub fn get_regeneration(&self, target: AttributeId) -> Option<&Regeneration> {
+        self.regenerations
+            .iter()
+            .find(|regeneration| regeneration.get_target() == target)
+    }
+}
+
+pub fn tick_attribute_regeneration_system(
+    mut query: Query<(
+        &mut AttributeRegeneration,
+        &mut AttributeSet,
+        Option<&GameplayTagContainer>,
+        Option<&GameplayTimeScale>,
+    )>,
+) {
+    for (mut regeneration, mut attr_set, tags, time_scale) in query.iter_mut() {
+        let ticks = time_scale.map_or(1, GameplayTimeScale::get_pending_ticks);
+        for _ in 0..ticks {
+            for entry in regeneration.regenerations.iter_mut() {
+                entry.tick(&mut attr_set, tags);
+            }
+        }
+    }
+}
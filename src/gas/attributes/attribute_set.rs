@@ -12,6 +12,7 @@ pub type AttributePostExecute = fn(&mut AttributeSet, AttributeId, f64, f64);
 pub struct AttributeSet {
     attributes: Vec<Option<Attribute>>,
     post_execute: Option<AttributePostExecute>,
+    change_cause: Option<AttributeChangeCause>,
     dirty: bool,
 }
 
@@ -20,6 +21,7 @@ impl Default for AttributeSet {
         Self {
             attributes: vec![None; ATTRIBUTE_SET_SIZE],
             post_execute: None,
+            change_cause: None,
             dirty: true,
         }
     }
@@ -50,10 +52,29 @@ impl AttributeSet {
         }
     }
 
+    pub fn get_attribute_clamp(&self, id: AttributeId) -> Option<AttributeClamp> {
+        let index = id.to_index();
+        debug_assert!(index < self.attributes.len());
+        self.attributes[index].as_ref().map(Attribute::get_clamp)
+    }
+
     pub fn set_post_execute(&mut self, post_execute: Option<AttributePostExecute>) {
         self.post_execute = post_execute;
     }
 
+    /// Cause recorded on attributes changed by following instant modifiers, until reset to `None`.
+    pub fn set_change_cause(&mut self, cause: Option<AttributeChangeCause>) {
+        self.change_cause = cause;
+    }
+
+    pub fn get_last_change_cause(&self, id: AttributeId) -> Option<AttributeChangeCause> {
+        let index = id.to_index();
+        debug_assert!(index < self.attributes.len());
+        self.attributes[index]
+            .as_ref()
+            .and_then(Attribute::get_last_change_cause)
+    }
+
     pub fn recalculate_attribute(&mut self, id: AttributeId) {
         let index = id.to_index();
         debug_assert!(index < self.attributes.len());
@@ -86,12 +107,42 @@ impl AttributeSet {
         None
     }
 
+    /// Like [`Self::get_current_value`], but usable through a shared reference.
+    pub fn calculate_current_value(&self, id: AttributeId) -> Option<f64> {
+        let index = id.to_index();
+        debug_assert!(index < self.attributes.len());
+        self.attributes[index]
+            .as_ref()
+            .map(Attribute::calculate_current_value)
+    }
+
+    pub fn get_base_value(&self, id: AttributeId) -> Option<f64> {
+        let index = id.to_index();
+        debug_assert!(index < self.attributes.len());
+        self.attributes[index]
+            .as_ref()
+            .map(Attribute::get_base_value)
+    }
+
+    /// Returns the base value, every contributing modifier, the clamp and the final value of `id`.
+    ///
+    /// Source effect asset tags are left empty; see [`crate::get_attribute_breakdown`].
+    pub fn get_attribute_breakdown(&mut self, id: AttributeId) -> Option<AttributeBreakdown> {
+        // `make_breakdown` recalculates just this attribute.
+        let index = id.to_index();
+        debug_assert!(index < self.attributes.len());
+        self.attributes[index]
+            .as_mut()
+            .map(|attr| attr.make_breakdown(id))
+    }
+
     pub fn apply_instant_modifier(&mut self, spec: &ModifierSpec) {
         let index = spec.get_id().to_index();
         debug_assert!(index < self.attributes.len());
         let old_value = self.get_current_value(spec.get_id());
         if let Some(attr) = &mut self.attributes[index] {
             attr.modify_base_value(spec);
+            attr.set_last_change_cause(self.change_cause);
             self.mark_dirty();
         }
 
@@ -109,6 +160,7 @@ impl AttributeSet {
         debug_assert!(index < self.attributes.len());
         if let Some(attr) = &mut self.attributes[index] {
             attr.apply_modifier_spec(spec, handle);
+            attr.set_last_change_cause(Some(AttributeChangeCause::ActiveEffect(handle)));
             self.mark_dirty();
         }
     }
@@ -119,6 +171,7 @@ impl AttributeSet {
             let len_before = attr.modifier_count();
             attr.remove_modifier_by_handle(handle);
             if attr.modifier_count() != len_before {
+                attr.set_last_change_cause(Some(AttributeChangeCause::ActiveEffect(handle)));
                 removed_from_any = true;
             }
         }
@@ -140,6 +193,7 @@ impl AttributeSet {
                 let len_before = attr.modifier_count();
                 attr.remove_modifier_by_handle(handle);
                 if attr.modifier_count() != len_before {
+                    attr.set_last_change_cause(Some(AttributeChangeCause::ActiveEffect(handle)));
                     removed_from_any = true;
                 }
             }
@@ -164,6 +218,57 @@ impl AttributeSet {
         AttributeSetSnapshot::new(new_attrs, source_entity)
     }
 
+    /// Snapshot holding only `ids`, for abilities that know exactly what their magnitudes read.
+    pub fn make_partial_snapshot(
+        &mut self,
+        source_entity: Entity,
+        ids: impl IntoIterator<Item = AttributeId>,
+    ) -> AttributeSetSnapshot {
+        self.recalculate_all();
+
+        let mut new_attrs: Vec<Option<Box<AttributeSnapshot>>> = Vec::new();
+        for id in ids {
+            let index = id.to_index();
+            debug_assert!(index < self.attributes.len());
+            let Some(attr) = &self.attributes[index] else {
+                continue;
+            };
+            if new_attrs.len() <= index {
+                new_attrs.resize(index + 1, None);
+            }
+            new_attrs[index] = Some(Box::new(attr.make_snapshot()));
+        }
+
+        AttributeSetSnapshot::new(new_attrs, source_entity)
+    }
+
+    /// FNV-1a hash over the id, base and current value bits of every initialized attribute.
+    ///
+    /// Stable across runs and platforms, so lockstep peers can compare it to detect desyncs.
+    pub fn make_state_hash(&mut self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        self.recalculate_all();
+
+        let mut hash = FNV_OFFSET;
+        for (index, attr) in self.attributes.iter_mut().enumerate() {
+            let Some(attr) = attr else {
+                continue;
+            };
+            let words = [
+                index as u64,
+                attr.get_base_value().to_bits(),
+                attr.get_current_value().to_bits(),
+            ];
+            for byte in words.iter().flat_map(|word| word.to_le_bytes()) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+
     fn mark_dirty(&mut self) {
         self.dirty = true;
     }
@@ -1,11 +1,18 @@
 use super::attribute_id_manager::AttributeId;
 use super::attribute_snapshot::AttributeSnapshot;
+use crate::gameplay_tags::{
+    GameplayTag, GameplayTagBits, GameplayTagContainer, tag_bits_from_tags,
+};
 use bevy::prelude::*;
 
+/// Captured attribute values of `source_entity`, optionally with its tags.
+///
+/// Partial snapshots only hold the requested attributes; every other id reads as `None`.
 #[derive(Component, Clone)]
 pub struct AttributeSetSnapshot {
     snapshot: Box<[Option<Box<AttributeSnapshot>>]>,
     source_entity: Entity,
+    tags: Option<GameplayTagContainer>,
 }
 
 impl AttributeSetSnapshot {
@@ -13,28 +20,151 @@ impl AttributeSetSnapshot {
         Self {
             snapshot: snapshot.into_boxed_slice(),
             source_entity,
+            tags: None,
         }
     }
 
+    /// Captures `tags` so tag requirements can be checked against the snapshot state.
+    pub fn with_tags(mut self, tags: &GameplayTagContainer) -> Self {
+        self.tags = Some(tags.clone());
+        self
+    }
+
+    pub fn get_attribute(&self, id: AttributeId) -> Option<AttributeSnapshot> {
+        self.snapshot
+            .get(id.to_index())
+            .and_then(|attr| attr.as_deref().copied())
+    }
+
     pub fn get_current_value(&self, id: AttributeId) -> Option<f64> {
-        let index = id.to_index();
-        debug_assert!(index < self.snapshot.len());
-        if let Some(attr) = &self.snapshot[index] {
-            return Some(attr.current());
-        }
-        None
+        self.get_attribute(id).map(|attr| attr.current())
     }
 
     pub fn get_base_value(&self, id: AttributeId) -> Option<f64> {
-        let index = id.to_index();
-        debug_assert!(index < self.snapshot.len());
-        if let Some(attr) = &self.snapshot[index] {
-            return Some(attr.base());
-        }
-        None
+        self.get_attribute(id).map(|attr| attr.base())
     }
 
     pub fn get_source_entity(&self) -> Entity {
         self.source_entity
     }
+
+    pub fn get_tags(&self) -> Option<&GameplayTagContainer> {
+        self.tags.as_ref()
+    }
+
+    /// Attribute and tag changes from `self` (before) to `after`.
+    ///
+    /// Tag changes are only reported when both snapshots captured tags.
+    pub fn diff(&self, after: &AttributeSetSnapshot) -> AttributeSetSnapshotDiff {
+        let len = self.snapshot.len().max(after.snapshot.len());
+        let changes = (0..len)
+            .filter_map(|index| {
+                let id = AttributeId::new(index as u16);
+                let before_attr = self.get_attribute(id);
+                let after_attr = after.get_attribute(id);
+                (before_attr != after_attr).then_some(AttributeSnapshotChange {
+                    id,
+                    before: before_attr,
+                    after: after_attr,
+                })
+            })
+            .collect();
+
+        let mut added_tag_bits = GameplayTagBits::default();
+        let mut removed_tag_bits = GameplayTagBits::default();
+        if let (Some(before_tags), Some(after_tags)) = (&self.tags, &after.tags) {
+            let blocks = before_tags
+                .get_tag_bits()
+                .iter()
+                .zip(after_tags.get_tag_bits().iter());
+            for (index, (before_block, after_block)) in blocks.enumerate() {
+                added_tag_bits[index] = after_block & !before_block;
+                removed_tag_bits[index] = before_block & !after_block;
+            }
+        }
+
+        AttributeSetSnapshotDiff {
+            changes,
+            added_tag_bits,
+            removed_tag_bits,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeSnapshotChange {
+    id: AttributeId,
+    before: Option<AttributeSnapshot>,
+    after: Option<AttributeSnapshot>,
+}
+
+impl AttributeSnapshotChange {
+    pub fn get_id(&self) -> AttributeId {
+        self.id
+    }
+
+    /// `None` when the attribute was not captured in the earlier snapshot.
+    pub fn get_before(&self) -> Option<AttributeSnapshot> {
+        self.before
+    }
+
+    /// `None` when the attribute was not captured in the later snapshot.
+    pub fn get_after(&self) -> Option<AttributeSnapshot> {
+        self.after
+    }
+
+    /// `after.current - before.current` when both sides were captured.
+    pub fn get_current_delta(&self) -> Option<f64> {
+        Some(self.after?.current() - self.before?.current())
+    }
+
+    pub fn get_base_delta(&self) -> Option<f64> {
+        Some(self.after?.base() - self.before?.base())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeSetSnapshotDiff {
+    changes: Vec<AttributeSnapshotChange>,
+    added_tag_bits: GameplayTagBits,
+    removed_tag_bits: GameplayTagBits,
+}
+
+impl AttributeSetSnapshotDiff {
+    /// Changed attributes, ordered by id.
+    pub fn get_changes(&self) -> &[AttributeSnapshotChange] {
+        &self.changes
+    }
+
+    pub fn get_change(&self, id: AttributeId) -> Option<&AttributeSnapshotChange> {
+        self.changes.iter().find(|change| change.id == id)
+    }
+
+    pub fn get_added_tag_bits(&self) -> &GameplayTagBits {
+        &self.added_tag_bits
+    }
+
+    pub fn get_removed_tag_bits(&self) -> &GameplayTagBits {
+        &self.removed_tag_bits
+    }
+
+    /// Also true for parents of an added tag that were not present before.
+    pub fn has_added_tag(&self, tag: &GameplayTag) -> bool {
+        has_tag_bit(&self.added_tag_bits, tag)
+    }
+
+    pub fn has_removed_tag(&self, tag: &GameplayTag) -> bool {
+        has_tag_bit(&self.removed_tag_bits, tag)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+            && self.added_tag_bits.iter().all(|block| *block == 0)
+            && self.removed_tag_bits.iter().all(|block| *block == 0)
+    }
+}
+
+fn has_tag_bit(tag_bits: &GameplayTagBits, tag: &GameplayTag) -> bool {
+    tag_bits_from_tags(std::slice::from_ref(tag))
+        .is_some_and(|bits| tag_bits.iter().zip(bits.iter()).any(|(a, b)| (a & b) != 0))
 }
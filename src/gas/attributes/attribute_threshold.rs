@@ -0,0 +1,273 @@
+use super::{AttributeId, AttributeSet};
+use crate::gameplay_tags::{GameplayTag, GameplayTagContainer, GameplayTagManager};
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThresholdValue {
+    Absolute(f64),
+    /// `ratio * current value of attribute`, e.g. 30% of MaxHealth.
+    RatioOf {
+        attribute: AttributeId,
+        ratio: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdDirection {
+    AtOrBelow,
+    AtOrAbove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeThresholdState {
+    Entered,
+    Exited,
+}
+
+/// Fired on the owner whenever one of its [`AttributeThresholds`] is entered or exited.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct AttributeThresholdEvent {
+    entity: Entity,
+    threshold_index: usize,
+    attribute: AttributeId,
+    state: AttributeThresholdState,
+    value: f64,
+}
+
+impl AttributeThresholdEvent {
+    pub fn new(
+        entity: Entity,
+        threshold_index: usize,
+        attribute: AttributeId,
+        state: AttributeThresholdState,
+        value: f64,
+    ) -> Self {
+        Self {
+            entity,
+            threshold_index,
+            attribute,
+            state,
+            value,
+        }
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Index of the threshold inside the owner's [`AttributeThresholds`].
+    pub fn get_threshold_index(&self) -> usize {
+        self.threshold_index
+    }
+
+    pub fn get_attribute(&self) -> AttributeId {
+        self.attribute
+    }
+
+    pub fn get_state(&self) -> AttributeThresholdState {
+        self.state
+    }
+
+    /// Attribute current value at the moment of crossing.
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Grants `granted_tags` while `attribute` is past `value` in `direction`.
+///
+/// Once entered, the threshold is only exited after the attribute moves `hysteresis` beyond the
+/// threshold value, so values hovering around it do not toggle every tick.
+#[derive(Debug, Clone)]
+pub struct AttributeThreshold {
+    attribute: AttributeId,
+    value: ThresholdValue,
+    direction: ThresholdDirection,
+    hysteresis: f64,
+    granted_tags: Vec<GameplayTag>,
+    active: bool,
+}
+
+impl AttributeThreshold {
+    pub fn new(
+        attribute: AttributeId,
+        value: ThresholdValue,
+        direction: ThresholdDirection,
+    ) -> Self {
+        Self {
+            attribute,
+            value,
+            direction,
+            hysteresis: 0.0,
+            granted_tags: Vec::new(),
+            active: false,
+        }
+    }
+
+    pub fn with_hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = hysteresis.max(0.0);
+        self
+    }
+
+    pub fn with_granted_tags(mut self, granted_tags: Vec<GameplayTag>) -> Self {
+        self.granted_tags = granted_tags;
+        self
+    }
+
+    pub fn get_attribute(&self) -> AttributeId {
+        self.attribute
+    }
+
+    pub fn get_value(&self) -> ThresholdValue {
+        self.value
+    }
+
+    pub fn get_direction(&self) -> ThresholdDirection {
+        self.direction
+    }
+
+    pub fn get_hysteresis(&self) -> f64 {
+        self.hysteresis
+    }
+
+    pub fn get_granted_tags(&self) -> &[GameplayTag] {
+        &self.granted_tags
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn resolve_value(&self, attr_set: &AttributeSet) -> Option<f64> {
+        match self.value {
+            ThresholdValue::Absolute(value) => Some(value),
+            ThresholdValue::RatioOf { attribute, ratio } => attr_set
+                .calculate_current_value(attribute)
+                .map(|value| value * ratio),
+        }
+    }
+
+    /// Returns the new state when `current` crosses the threshold, `None` otherwise.
+    fn evaluate(&self, current: f64, threshold: f64) -> Option<AttributeThresholdState> {
+        let (entered, exited) = match self.direction {
+            ThresholdDirection::AtOrBelow => {
+                (current <= threshold, current > threshold + self.hysteresis)
+            }
+            ThresholdDirection::AtOrAbove => {
+                (current >= threshold, current < threshold - self.hysteresis)
+            }
+        };
+        match self.active {
+            false if entered => Some(AttributeThresholdState::Entered),
+            true if exited => Some(AttributeThresholdState::Exited),
+            _ => None,
+        }
+    }
+}
+
+/// Removing this component also removes the granted tags of thresholds that are still entered.
+#[derive(Component, Debug, Clone, Default)]
+#[component(on_remove = remove_entered_threshold_tags)]
+pub struct AttributeThresholds {
+    thresholds: Vec<AttributeThreshold>,
+}
+
+impl AttributeThresholds {
+    pub fn new(thresholds: Vec<AttributeThreshold>) -> Self {
+        Self { thresholds }
+    }
+
+    pub fn add_threshold(&mut self, threshold: AttributeThreshold) {
+        self.thresholds.push(threshold);
+    }
+
+    pub fn get_thresholds(&self) -> &[AttributeThreshold] {
+        &self.thresholds
+    }
+}
+
+fn remove_entered_threshold_tags(mut world: DeferredWorld, context: HookContext) {
+    let Some(thresholds) = world.get::<AttributeThresholds>(context.entity) else {
+        return;
+    };
+    let granted_tags: Vec<GameplayTag> = thresholds
+        .thresholds
+        .iter()
+        .filter(|threshold| threshold.active)
+        .flat_map(|threshold| threshold.granted_tags.iter().copied())
+        .collect();
+    if granted_tags.is_empty() {
+        return;
+    }
+    world
+        .commands()
+        .run_system_cached_with(remove_granted_tags, (context.entity, granted_tags));
+}
+
+fn remove_granted_tags(
+    In((entity, granted_tags)): In<(Entity, Vec<GameplayTag>)>,
+    mut query: Query<&mut GameplayTagContainer>,
+    tag_manager: Res<GameplayTagManager>,
+) {
+    if let Ok(mut tag_container) = query.get_mut(entity) {
+        tag_container.remove_tags(&granted_tags, &tag_manager);
+    }
+}
+
+type ThresholdOwnerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut AttributeThresholds,
+        &'static AttributeSet,
+        Option<&'static mut GameplayTagContainer>,
+    ),
+    Or<(Changed<AttributeSet>, Changed<AttributeThresholds>)>,
+>;
+
+pub fn update_attribute_thresholds_system(
+    mut commands: Commands,
+    mut query: ThresholdOwnerQuery,
+    tag_manager: Res<GameplayTagManager>,
+) {
+    for (entity, mut thresholds, attr_set, mut tag_container) in query.iter_mut() {
+        for (index, threshold) in thresholds
+            .bypass_change_detection()
+            .thresholds
+            .iter_mut()
+            .enumerate()
+        {
+            let (Some(current), Some(threshold_value)) = (
+                attr_set.calculate_current_value(threshold.attribute),
+                threshold.resolve_value(attr_set),
+            ) else {
+                continue;
+            };
+            let Some(state) = threshold.evaluate(current, threshold_value) else {
+                continue;
+            };
+
+            threshold.active = state == AttributeThresholdState::Entered;
+            if let Some(tag_container) = tag_container.as_mut() {
+                match state {
+                    AttributeThresholdState::Entered => {
+                        tag_container.add_tags(&threshold.granted_tags, &tag_manager)
+                    }
+                    AttributeThresholdState::Exited => {
+                        tag_container.remove_tags(&threshold.granted_tags, &tag_manager)
+                    }
+                }
+            }
+            commands.trigger(AttributeThresholdEvent::new(
+                entity,
+                index,
+                threshold.attribute,
+                state,
+                current,
+            ));
+        }
+    }
+}
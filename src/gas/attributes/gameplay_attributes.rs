@@ -0,0 +1,28 @@
+use super::{AttributeIdError, AttributeIdRegister, AttributeSet};
+
+#[cfg(feature = "derive")]
+pub use bevy_tools_derive::GameplayAttributes;
+
+/// A plain struct of `f64` fields that maps one-to-one onto attributes in an [`AttributeSet`].
+///
+/// Usually implemented through `#[derive(GameplayAttributes)]`, which also generates the
+/// `Ids` resource with one [`AttributeId`](super::AttributeId) per field and typed accessors on it.
+pub trait GameplayAttributes: Default + Send + Sync + 'static {
+    type Ids: Copy + Send + Sync + 'static;
+
+    fn register_attribute_ids(
+        register: &mut AttributeIdRegister,
+    ) -> Result<Self::Ids, AttributeIdError>;
+
+    /// Initializes every field as an attribute, using the field value as base value.
+    fn initialize_attribute_set(&self, ids: &Self::Ids, attributes: &mut AttributeSet);
+
+    /// Reads the current value of every field back from `attributes`.
+    fn from_attribute_set(ids: &Self::Ids, attributes: &mut AttributeSet) -> Self;
+
+    fn make_attribute_set(&self, ids: &Self::Ids) -> AttributeSet {
+        let mut attributes = AttributeSet::default();
+        self.initialize_attribute_set(ids, &mut attributes);
+        attributes
+    }
+}
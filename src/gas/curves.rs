@@ -0,0 +1,7 @@
+mod curve_table;
+mod real_curve;
+mod scalable_float;
+
+pub use curve_table::*;
+pub use real_curve::*;
+pub use scalable_float::*;
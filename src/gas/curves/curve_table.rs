@@ -0,0 +1,161 @@
+use super::{CurveInterpolation, CurveKey, RealCurve};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::Resource;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurveTableError {
+    EmptyCsv,
+    InvalidCsvKey {
+        column: usize,
+        value: String,
+    },
+    InvalidCsvValue {
+        row: String,
+        column: usize,
+        value: String,
+    },
+    DuplicateCurve {
+        name: String,
+    },
+    InvalidRon {
+        message: String,
+    },
+}
+
+impl fmt::Display for CurveTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveTableError::EmptyCsv => write!(f, "curve table csv has no header row"),
+            CurveTableError::InvalidCsvKey { column, value } => {
+                write!(
+                    f,
+                    "curve table csv header column {column} is not a number: '{value}'"
+                )
+            }
+            CurveTableError::InvalidCsvValue { row, column, value } => write!(
+                f,
+                "curve table csv row '{row}' column {column} is not a number: '{value}'"
+            ),
+            CurveTableError::DuplicateCurve { name } => {
+                write!(f, "curve table defines curve '{name}' more than once")
+            }
+            CurveTableError::InvalidRon { message } => {
+                write!(f, "curve table ron is invalid: {message}")
+            }
+        }
+    }
+}
+
+impl Error for CurveTableError {}
+
+#[derive(Deserialize)]
+struct CurveDef {
+    #[serde(default)]
+    interpolation: CurveInterpolation,
+    keys: Vec<CurveKey>,
+}
+
+/// Named curves, shared as `Arc<RealCurve>` so definitions can hold on to the rows they sample.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CurveTable {
+    curves: HashMap<String, Arc<RealCurve>>,
+}
+
+impl CurveTable {
+    /// Parses a UE-style table: the header row holds the key times after a leading name
+    /// column, and every following row is `CurveName,value,value,...`. Empty cells are skipped.
+    pub fn from_csv(
+        text: &str,
+        interpolation: CurveInterpolation,
+    ) -> Result<Self, CurveTableError> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        let header = lines.next().ok_or(CurveTableError::EmptyCsv)?;
+        let times = header
+            .split(',')
+            .enumerate()
+            .skip(1)
+            .map(|(column, cell)| {
+                let cell = cell.trim();
+                cell.parse::<f64>()
+                    .map_err(|_| CurveTableError::InvalidCsvKey {
+                        column,
+                        value: cell.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut table = Self::default();
+        for line in lines {
+            let mut cells = line.split(',').map(str::trim);
+            let name = cells.next().unwrap_or_default();
+            let mut keys = Vec::with_capacity(times.len());
+            for (offset, (cell, &time)) in cells.zip(times.iter()).enumerate() {
+                if cell.is_empty() {
+                    continue;
+                }
+                let value = cell
+                    .parse::<f64>()
+                    .map_err(|_| CurveTableError::InvalidCsvValue {
+                        row: name.to_string(),
+                        column: offset + 1,
+                        value: cell.to_string(),
+                    })?;
+                keys.push(CurveKey::new(time, value));
+            }
+            table.insert_new(name, RealCurve::new(keys, interpolation))?;
+        }
+        Ok(table)
+    }
+
+    /// Parses a map of curve name to `(interpolation: Linear, keys: [(time: 1.0, value: 10.0)])`.
+    pub fn from_ron(text: &str) -> Result<Self, CurveTableError> {
+        let defs = ron::from_str::<BTreeMap<String, CurveDef>>(text).map_err(|err| {
+            CurveTableError::InvalidRon {
+                message: err.to_string(),
+            }
+        })?;
+
+        let mut table = Self::default();
+        for (name, def) in defs {
+            table.insert_new(&name, RealCurve::new(def.keys, def.interpolation))?;
+        }
+        Ok(table)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, curve: RealCurve) -> Arc<RealCurve> {
+        let curve = Arc::new(curve);
+        self.curves.insert(name.into(), curve.clone());
+        curve
+    }
+
+    pub fn get_curve(&self, name: &str) -> Option<&Arc<RealCurve>> {
+        self.curves.get(name)
+    }
+
+    pub fn evaluate(&self, name: &str, time: f64) -> Option<f64> {
+        self.get_curve(name).map(|curve| curve.evaluate(time))
+    }
+
+    pub fn len(&self) -> usize {
+        self.curves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.curves.is_empty()
+    }
+
+    fn insert_new(&mut self, name: &str, curve: RealCurve) -> Result<(), CurveTableError> {
+        if self.curves.contains_key(name) {
+            return Err(CurveTableError::DuplicateCurve {
+                name: name.to_string(),
+            });
+        }
+        self.insert(name, curve);
+        Ok(())
+    }
+}
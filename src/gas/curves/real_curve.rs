@@ -0,0 +1,108 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum CurveInterpolation {
+    #[default]
+    Linear,
+    Constant,
+    Cubic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct CurveKey {
+    time: f64,
+    value: f64,
+}
+
+impl CurveKey {
+    pub fn new(time: f64, value: f64) -> Self {
+        Self { time, value }
+    }
+
+    pub fn get_time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// A keyframed `f64` curve, usually keyed by effect or ability level.
+///
+/// Sampling before the first key or after the last key returns that key's value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealCurve {
+    keys: Vec<CurveKey>,
+    interpolation: CurveInterpolation,
+}
+
+impl RealCurve {
+    pub fn new(mut keys: Vec<CurveKey>, interpolation: CurveInterpolation) -> Self {
+        keys.retain(|key| key.time.is_finite() && key.value.is_finite());
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        keys.dedup_by(|later, earlier| later.time == earlier.time);
+        Self {
+            keys,
+            interpolation,
+        }
+    }
+
+    pub fn get_keys(&self) -> &[CurveKey] {
+        &self.keys
+    }
+
+    pub fn get_interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    pub fn evaluate(&self, time: f64) -> f64 {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return 0.0;
+        };
+        if time <= first.time {
+            return first.value;
+        }
+        if time >= last.time {
+            return last.value;
+        }
+
+        // `time` is strictly inside the key range, so both neighbours exist.
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let prev = next - 1;
+        let (a, b) = (self.keys[prev], self.keys[next]);
+        let alpha = (time - a.time) / (b.time - a.time);
+
+        match self.interpolation {
+            CurveInterpolation::Constant => a.value,
+            CurveInterpolation::Linear => a.value + (b.value - a.value) * alpha,
+            CurveInterpolation::Cubic => {
+                let span = b.time - a.time;
+                let tangent_a = self.tangent(prev) * span;
+                let tangent_b = self.tangent(next) * span;
+                hermite(a.value, tangent_a, b.value, tangent_b, alpha)
+            }
+        }
+    }
+
+    /// Finite-difference (Catmull-Rom style) slope at `index`; end keys use their single neighbour.
+    fn tangent(&self, index: usize) -> f64 {
+        let prev = self.keys[index.saturating_sub(1)];
+        let next = self.keys[(index + 1).min(self.keys.len() - 1)];
+        let span = next.time - prev.time;
+        if span <= 0.0 {
+            0.0
+        } else {
+            (next.value - prev.value) / span
+        }
+    }
+}
+
+fn hermite(p0: f64, m0: f64, p1: f64, m1: f64, t: f64) -> f64 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * p0
+        + (t3 - 2.0 * t2 + t) * m0
+        + (-2.0 * t3 + 3.0 * t2) * p1
+        + (t3 - t2) * m1
+}
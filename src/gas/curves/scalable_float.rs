@@ -0,0 +1,40 @@
+use super::RealCurve;
+use std::sync::Arc;
+
+/// `coefficient * curve(level)`, or just `coefficient` when no curve row is set.
+#[derive(Debug, Clone)]
+pub struct ScalableFloat {
+    coefficient: f64,
+    curve: Option<Arc<RealCurve>>,
+}
+
+impl ScalableFloat {
+    pub fn new(coefficient: f64, curve: Arc<RealCurve>) -> Self {
+        Self {
+            coefficient,
+            curve: Some(curve),
+        }
+    }
+
+    pub fn flat(value: f64) -> Self {
+        Self {
+            coefficient: value,
+            curve: None,
+        }
+    }
+
+    pub fn get_coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    pub fn get_curve(&self) -> Option<&Arc<RealCurve>> {
+        self.curve.as_ref()
+    }
+
+    pub fn get_value_at_level(&self, level: u32) -> f64 {
+        match &self.curve {
+            Some(curve) => self.coefficient * curve.evaluate(level as f64),
+            None => self.coefficient,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod gameplay_definition;
+mod gameplay_definition_assets;
+
+pub use gameplay_definition::*;
+pub use gameplay_definition_assets::*;
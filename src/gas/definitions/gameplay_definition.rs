@@ -0,0 +1,747 @@
+use crate::attributes::{AttributeId, AttributeIdManager};
+use crate::curves::{CurveTable, RealCurve, ScalableFloat};
+use crate::gameplay_abilities::{
+    AbilityTags, AbilityTaskDef, AbilityTaskOnFinishedDef, GameplayAbility,
+};
+use crate::gameplay_effects::{
+    ConditionalGameplayEffect, EffectDurationTicks, EffectPeriodTicks, EffectPeriodUnit,
+    EffectTags, GameplayEffect, GameplayEffectImmunityQuery, StackDurationPolicy,
+    StackExpirationPolicy, StackMagnitudePolicy, StackOverflowPolicy, StackPeriodPolicy,
+    StackingPolicy, StackingType, TagRequirements,
+};
+use crate::gameplay_tags::{GameplayTag, GameplayTagManager};
+use crate::modifiers::{
+    AttributeBasedMagnitude, AttributeCaptureSide, AttributeCaptureValue, Modifier,
+    ModifierMagnitude, ModifierOperation, SetByCallerMagnitude,
+};
+use crate::unique_names::UniqueNamePool;
+use bevy::platform::collections::HashMap;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameplayDefinitionError {
+    Io {
+        message: String,
+    },
+    InvalidRon {
+        message: String,
+    },
+    UnknownTag {
+        name: String,
+    },
+    UnknownAttribute {
+        name: String,
+    },
+    UnknownCurve {
+        name: String,
+    },
+    /// A `Path` reference that is not a loaded effect definition.
+    UnknownEffect {
+        path: String,
+    },
+    /// A referenced effect definition failed to load or resolve.
+    DependencyFailed {
+        path: String,
+    },
+}
+
+impl fmt::Display for GameplayDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameplayDefinitionError::Io { message } => {
+                write!(f, "gameplay definition could not be read: {message}")
+            }
+            GameplayDefinitionError::InvalidRon { message } => {
+                write!(f, "gameplay definition ron is invalid: {message}")
+            }
+            GameplayDefinitionError::UnknownTag { name } => {
+                write!(f, "gameplay definition references unknown tag '{name}'")
+            }
+            GameplayDefinitionError::UnknownAttribute { name } => {
+                write!(
+                    f,
+                    "gameplay definition references unknown attribute '{name}'"
+                )
+            }
+            GameplayDefinitionError::UnknownCurve { name } => {
+                write!(f, "gameplay definition references unknown curve '{name}'")
+            }
+            GameplayDefinitionError::UnknownEffect { path } => {
+                write!(f, "gameplay definition references unknown effect '{path}'")
+            }
+            GameplayDefinitionError::DependencyFailed { path } => {
+                write!(f, "gameplay definition dependency '{path}' failed to load")
+            }
+        }
+    }
+}
+
+impl Error for GameplayDefinitionError {}
+
+/// Resolves names in definitions to runtime ids. Unknown tags and attributes are errors; they
+/// must be registered before definitions are resolved.
+pub struct GameplayDefinitionResolver<'a> {
+    names: &'a mut UniqueNamePool,
+    tag_manager: &'a GameplayTagManager,
+    attribute_ids: &'a AttributeIdManager,
+    curve_table: Option<&'a CurveTable>,
+    effects: HashMap<String, Arc<GameplayEffect>>,
+}
+
+impl<'a> GameplayDefinitionResolver<'a> {
+    pub fn new(
+        names: &'a mut UniqueNamePool,
+        tag_manager: &'a GameplayTagManager,
+        attribute_ids: &'a AttributeIdManager,
+    ) -> Self {
+        Self {
+            names,
+            tag_manager,
+            attribute_ids,
+            curve_table: None,
+            effects: HashMap::new(),
+        }
+    }
+
+    /// Table for `Curve` magnitudes; without it every curve reference is unknown.
+    pub fn with_curve_table(mut self, curve_table: &'a CurveTable) -> Self {
+        self.curve_table = Some(curve_table);
+        self
+    }
+
+    /// Effect returned for `Path(path)` references.
+    pub fn with_effect(mut self, path: impl Into<String>, effect: Arc<GameplayEffect>) -> Self {
+        self.effects.insert(path.into(), effect);
+        self
+    }
+
+    pub fn resolve_tag(&self, name: &str) -> Result<GameplayTag, GameplayDefinitionError> {
+        self.names
+            .find_name(name)
+            .and_then(|unique_name| self.tag_manager.get_tag(unique_name))
+            .ok_or_else(|| GameplayDefinitionError::UnknownTag {
+                name: name.to_string(),
+            })
+    }
+
+    pub fn resolve_attribute(&self, name: &str) -> Result<AttributeId, GameplayDefinitionError> {
+        self.names
+            .find_name(name)
+            .and_then(|unique_name| self.attribute_ids.get_attribute_id(unique_name))
+            .ok_or_else(|| GameplayDefinitionError::UnknownAttribute {
+                name: name.to_string(),
+            })
+    }
+
+    fn resolve_tags(&self, names: &[String]) -> Result<Vec<GameplayTag>, GameplayDefinitionError> {
+        names.iter().map(|name| self.resolve_tag(name)).collect()
+    }
+
+    fn resolve_curve(&self, name: &str) -> Result<Arc<RealCurve>, GameplayDefinitionError> {
+        self.curve_table
+            .and_then(|table| table.get_curve(name))
+            .cloned()
+            .ok_or_else(|| GameplayDefinitionError::UnknownCurve {
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Name-based [`TagRequirements`]: `(require: ["State.Burning"], ignore: ["State.Immune"])`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TagRequirementsDefinition {
+    pub require: Vec<String>,
+    pub ignore: Vec<String>,
+}
+
+impl TagRequirementsDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &GameplayDefinitionResolver,
+    ) -> Result<TagRequirements, GameplayDefinitionError> {
+        Ok(TagRequirements::new(
+            resolver.resolve_tags(&self.require)?,
+            resolver.resolve_tags(&self.ignore)?,
+        ))
+    }
+}
+
+fn default_coefficient() -> f64 {
+    1.0
+}
+
+fn default_capture_value() -> AttributeCaptureValue {
+    AttributeCaptureValue::Current
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum MagnitudeDefinition {
+    Flat(f64),
+    /// `coefficient * curve(level)`, with `curve` looked up in the resolver's [`CurveTable`].
+    Curve {
+        curve: String,
+        #[serde(default = "default_coefficient")]
+        coefficient: f64,
+    },
+    AttributeBased {
+        attribute: String,
+        side: AttributeCaptureSide,
+        #[serde(default)]
+        snapshot: bool,
+        #[serde(default = "default_capture_value")]
+        value: AttributeCaptureValue,
+        #[serde(default = "default_coefficient")]
+        coefficient: f64,
+        #[serde(default)]
+        pre_multiply_additive: f64,
+        #[serde(default)]
+        post_multiply_additive: f64,
+    },
+    SetByCallerTag {
+        tag: String,
+        #[serde(default)]
+        fallback: Option<f64>,
+    },
+    /// Keys by name; the name is added to the pool since set-by-caller names need no registration.
+    SetByCallerName {
+        name: String,
+        #[serde(default)]
+        fallback: Option<f64>,
+    },
+}
+
+impl MagnitudeDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<ModifierMagnitude, GameplayDefinitionError> {
+        let set_by_caller = |magnitude: SetByCallerMagnitude, fallback: Option<f64>| {
+            ModifierMagnitude::SetByCaller(match fallback {
+                Some(fallback) => magnitude.with_fallback(fallback),
+                None => magnitude,
+            })
+        };
+
+        Ok(match self {
+            MagnitudeDefinition::Flat(value) => ModifierMagnitude::Flat(*value),
+            MagnitudeDefinition::Curve { curve, coefficient } => ModifierMagnitude::ScalableFloat(
+                ScalableFloat::new(*coefficient, resolver.resolve_curve(curve)?),
+            ),
+            MagnitudeDefinition::AttributeBased {
+                attribute,
+                side,
+                snapshot,
+                value,
+                coefficient,
+                pre_multiply_additive,
+                post_multiply_additive,
+            } => ModifierMagnitude::AttributeBased(
+                AttributeBasedMagnitude::new(
+                    resolver.resolve_attribute(attribute)?,
+                    *side,
+                    *snapshot,
+                    *value,
+                )
+                .with_coefficient(*coefficient)
+                .with_pre_multiply_additive(*pre_multiply_additive)
+                .with_post_multiply_additive(*post_multiply_additive),
+            ),
+            MagnitudeDefinition::SetByCallerTag { tag, fallback } => set_by_caller(
+                SetByCallerMagnitude::new(resolver.resolve_tag(tag)?),
+                *fallback,
+            ),
+            MagnitudeDefinition::SetByCallerName { name, fallback } => set_by_caller(
+                SetByCallerMagnitude::new(resolver.names.new_name(name)),
+                *fallback,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModifierDefinition {
+    pub attribute: String,
+    pub operation: ModifierOperation,
+    pub magnitude: MagnitudeDefinition,
+    #[serde(default)]
+    pub source_tags: TagRequirementsDefinition,
+    #[serde(default)]
+    pub target_tags: TagRequirementsDefinition,
+}
+
+impl ModifierDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<Modifier, GameplayDefinitionError> {
+        Ok(Modifier::new(
+            resolver.resolve_attribute(&self.attribute)?,
+            self.operation,
+            self.magnitude.resolve(resolver)?,
+        )
+        .with_source_tag_requirements(self.source_tags.resolve(resolver)?)
+        .with_target_tag_requirements(self.target_tags.resolve(resolver)?))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub enum DurationDefinition {
+    #[default]
+    Instant,
+    Ticks(MagnitudeDefinition),
+    Seconds(MagnitudeDefinition),
+    Infinite,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PeriodDefinition {
+    pub period: MagnitudeDefinition,
+    #[serde(default)]
+    pub unit: EffectPeriodUnit,
+    #[serde(default)]
+    pub execute_on_applied: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StackingDefinition {
+    pub stacking_type: StackingType,
+    pub stack_limit: u32,
+    pub magnitude_policy: StackMagnitudeDefinition,
+    pub duration_policy: StackDurationPolicy,
+    pub period_policy: StackPeriodPolicy,
+    pub overflow_policy: StackOverflowPolicy,
+    pub expiration_policy: StackExpirationPolicy,
+    pub clear_stacks_on_overflow: bool,
+}
+
+impl Default for StackingDefinition {
+    /// Same as [`StackingPolicy::non_stacking`].
+    fn default() -> Self {
+        let policy = StackingPolicy::non_stacking();
+        Self {
+            stacking_type: policy.get_stacking_type(),
+            stack_limit: policy.get_stack_limit(),
+            magnitude_policy: StackMagnitudeDefinition::None,
+            duration_policy: policy.get_duration_policy(),
+            period_policy: policy.get_period_policy(),
+            overflow_policy: policy.get_overflow_policy(),
+            expiration_policy: policy.get_expiration_policy(),
+            clear_stacks_on_overflow: policy.is_clear_stacks_on_overflow(),
+        }
+    }
+}
+
+impl StackingDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &GameplayDefinitionResolver,
+    ) -> Result<StackingPolicy, GameplayDefinitionError> {
+        let policy = StackingPolicy::new(
+            self.stacking_type,
+            self.stack_limit,
+            self.magnitude_policy.resolve(resolver)?,
+            self.duration_policy,
+            self.period_policy,
+            self.overflow_policy,
+            self.expiration_policy,
+        );
+        Ok(if self.clear_stacks_on_overflow {
+            policy.with_clear_stacks_on_overflow()
+        } else {
+            policy
+        })
+    }
+}
+
+/// Name-based [`StackMagnitudePolicy`]; `Custom` calculations can only be set from code.
+#[derive(Debug, Clone, Deserialize)]
+pub enum StackMagnitudeDefinition {
+    None,
+    Linear,
+    Diminishing {
+        additional_stack_scale: f64,
+    },
+    /// Multiplier sampled at the stack count from the resolver's [`CurveTable`].
+    Curve(String),
+}
+
+impl StackMagnitudeDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &GameplayDefinitionResolver,
+    ) -> Result<StackMagnitudePolicy, GameplayDefinitionError> {
+        Ok(match self {
+            StackMagnitudeDefinition::None => StackMagnitudePolicy::None,
+            StackMagnitudeDefinition::Linear => StackMagnitudePolicy::Linear,
+            StackMagnitudeDefinition::Diminishing {
+                additional_stack_scale,
+            } => StackMagnitudePolicy::Diminishing {
+                additional_stack_scale: *additional_stack_scale,
+            },
+            StackMagnitudeDefinition::Curve(curve) => {
+                StackMagnitudePolicy::Curve(resolver.resolve_curve(curve)?)
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ImmunityQueryDefinition {
+    pub source_tags: TagRequirementsDefinition,
+    pub effect_tags: TagRequirementsDefinition,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct EffectTagsDefinition {
+    pub asset_tags: Vec<String>,
+    pub granted_tags: Vec<String>,
+    pub source_application_tags: TagRequirementsDefinition,
+    pub target_application_tags: TagRequirementsDefinition,
+    pub source_ongoing_tags: TagRequirementsDefinition,
+    pub target_ongoing_tags: TagRequirementsDefinition,
+    pub source_removal_tags: TagRequirementsDefinition,
+    pub target_removal_tags: TagRequirementsDefinition,
+    pub granted_application_immunity: Vec<ImmunityQueryDefinition>,
+    pub remove_effects_with_tags: Vec<String>,
+}
+
+impl EffectTagsDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &GameplayDefinitionResolver,
+    ) -> Result<EffectTags, GameplayDefinitionError> {
+        let immunity = self
+            .granted_application_immunity
+            .iter()
+            .map(|query| {
+                Ok(GameplayEffectImmunityQuery::new(
+                    query.source_tags.resolve(resolver)?,
+                    query.effect_tags.resolve(resolver)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, GameplayDefinitionError>>()?;
+
+        Ok(EffectTags::new(
+            resolver.resolve_tags(&self.asset_tags)?,
+            resolver.resolve_tags(&self.granted_tags)?,
+            self.source_application_tags.resolve(resolver)?,
+            self.target_application_tags.resolve(resolver)?,
+            self.source_ongoing_tags.resolve(resolver)?,
+            self.target_ongoing_tags.resolve(resolver)?,
+            self.source_removal_tags.resolve(resolver)?,
+            self.target_removal_tags.resolve(resolver)?,
+            immunity,
+            resolver.resolve_tags(&self.remove_effects_with_tags)?,
+        ))
+    }
+}
+
+/// An effect written in place, or the asset path of another effect definition.
+#[derive(Debug, Clone, Deserialize)]
+pub enum EffectReferenceDefinition {
+    Inline(Box<GameplayEffectDefinition>),
+    Path(String),
+}
+
+impl EffectReferenceDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<Arc<GameplayEffect>, GameplayDefinitionError> {
+        match self {
+            EffectReferenceDefinition::Inline(definition) => {
+                Ok(Arc::new(definition.resolve(resolver)?))
+            }
+            EffectReferenceDefinition::Path(path) => resolver
+                .effects
+                .get(path)
+                .cloned()
+                .ok_or_else(|| GameplayDefinitionError::UnknownEffect { path: path.clone() }),
+        }
+    }
+
+    fn collect_effect_paths<'a>(&'a self, paths: &mut Vec<&'a str>) {
+        match self {
+            EffectReferenceDefinition::Inline(definition) => {
+                definition.collect_effect_paths(paths);
+            }
+            EffectReferenceDefinition::Path(path) => paths.push(path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConditionalEffectDefinition {
+    pub effect: EffectReferenceDefinition,
+    #[serde(default)]
+    pub source_tags: TagRequirementsDefinition,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+/// RON form of [`GameplayEffect`]. Executions are code and are not part of the definition.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameplayEffectDefinition {
+    #[serde(default)]
+    pub modifiers: Vec<ModifierDefinition>,
+    #[serde(default)]
+    pub duration: DurationDefinition,
+    #[serde(default)]
+    pub period: Option<PeriodDefinition>,
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    #[serde(default)]
+    pub stacking: StackingDefinition,
+    #[serde(default)]
+    pub tags: EffectTagsDefinition,
+    #[serde(default)]
+    pub conditional_effects: Vec<ConditionalEffectDefinition>,
+    #[serde(default)]
+    pub overflow_effects: Vec<EffectReferenceDefinition>,
+}
+
+impl GameplayEffectDefinition {
+    pub fn from_ron(text: &str) -> Result<Self, GameplayDefinitionError> {
+        ron::from_str(text).map_err(|err| GameplayDefinitionError::InvalidRon {
+            message: err.to_string(),
+        })
+    }
+
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<GameplayEffect, GameplayDefinitionError> {
+        let modifiers = self
+            .modifiers
+            .iter()
+            .map(|modifier| modifier.resolve(resolver))
+            .collect::<Result<Vec<_>, _>>()?;
+        let duration = match &self.duration {
+            DurationDefinition::Instant => EffectDurationTicks::Instant,
+            DurationDefinition::Ticks(magnitude) => {
+                EffectDurationTicks::DurationTicks(magnitude.resolve(resolver)?)
+            }
+            DurationDefinition::Seconds(magnitude) => {
+                EffectDurationTicks::DurationSeconds(magnitude.resolve(resolver)?)
+            }
+            DurationDefinition::Infinite => EffectDurationTicks::Infinite,
+        };
+        let period = match &self.period {
+            Some(period) => {
+                let magnitude = period.period.resolve(resolver)?;
+                Some(
+                    EffectPeriodTicks::new(magnitude, period.execute_on_applied)
+                        .with_unit(period.unit),
+                )
+            }
+            None => None,
+        };
+
+        let mut effect = GameplayEffect::new(
+            modifiers,
+            duration,
+            period,
+            self.probability,
+            self.stacking.resolve(resolver)?,
+            self.tags.resolve(resolver)?,
+        );
+        for conditional in &self.conditional_effects {
+            effect = effect.with_conditional_effect(ConditionalGameplayEffect::new(
+                conditional.effect.resolve(resolver)?,
+                conditional.source_tags.resolve(resolver)?,
+            ));
+        }
+        for overflow_effect in &self.overflow_effects {
+            effect = effect.with_overflow_effect(overflow_effect.resolve(resolver)?);
+        }
+        Ok(effect)
+    }
+
+    /// Asset paths of every `Path` reference, including those inside inline effects.
+    pub fn get_effect_paths(&self) -> Vec<&str> {
+        let mut paths = Vec::new();
+        self.collect_effect_paths(&mut paths);
+        paths
+    }
+
+    fn collect_effect_paths<'a>(&'a self, paths: &mut Vec<&'a str>) {
+        for conditional in &self.conditional_effects {
+            conditional.effect.collect_effect_paths(paths);
+        }
+        for overflow_effect in &self.overflow_effects {
+            overflow_effect.collect_effect_paths(paths);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AbilityTagsDefinition {
+    pub ability_asset_tags: Vec<String>,
+    pub cancel_abilities_with_tags: Vec<String>,
+    pub block_abilities_with_tags: Vec<String>,
+    pub activation_required_tags: Vec<String>,
+    pub activation_blocked_tags: Vec<String>,
+}
+
+/// `ActivateAbility` is not supported: ability spec handles only exist at runtime.
+#[derive(Debug, Clone, Deserialize)]
+pub enum AbilityTaskOnFinishedDefinition {
+    None,
+    EndAbility,
+    EmitEvent { event_id: String },
+    ApplyGameplayEffectToTarget { effect: EffectReferenceDefinition },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum AbilityTaskDefinition {
+    Instant {
+        on_finished: AbilityTaskOnFinishedDefinition,
+    },
+    WaitTicks {
+        ticks: u32,
+        on_finished: AbilityTaskOnFinishedDefinition,
+    },
+}
+
+impl AbilityTaskDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<AbilityTaskDef, GameplayDefinitionError> {
+        Ok(match self {
+            AbilityTaskDefinition::Instant { on_finished } => {
+                AbilityTaskDef::instant(on_finished.resolve(resolver)?)
+            }
+            AbilityTaskDefinition::WaitTicks { ticks, on_finished } => {
+                AbilityTaskDef::wait_ticks(*ticks, on_finished.resolve(resolver)?)
+            }
+        })
+    }
+
+    fn get_on_finished(&self) -> &AbilityTaskOnFinishedDefinition {
+        match self {
+            AbilityTaskDefinition::Instant { on_finished }
+            | AbilityTaskDefinition::WaitTicks { on_finished, .. } => on_finished,
+        }
+    }
+}
+
+impl AbilityTaskOnFinishedDefinition {
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<AbilityTaskOnFinishedDef, GameplayDefinitionError> {
+        Ok(match self {
+            AbilityTaskOnFinishedDefinition::None => AbilityTaskOnFinishedDef::None,
+            AbilityTaskOnFinishedDefinition::EndAbility => AbilityTaskOnFinishedDef::EndAbility,
+            AbilityTaskOnFinishedDefinition::EmitEvent { event_id } => {
+                AbilityTaskOnFinishedDef::EmitEvent {
+                    event_id: resolver.names.new_name(event_id),
+                }
+            }
+            AbilityTaskOnFinishedDefinition::ApplyGameplayEffectToTarget { effect } => {
+                AbilityTaskOnFinishedDef::ApplyGameplayEffectToTarget {
+                    effect: effect.resolve(resolver)?,
+                }
+            }
+        })
+    }
+}
+
+/// RON form of [`GameplayAbility`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GameplayAbilityDefinition {
+    pub tags: AbilityTagsDefinition,
+    pub startup_tasks: Vec<AbilityTaskDefinition>,
+    pub cooldown: Option<EffectReferenceDefinition>,
+    pub cost: Option<EffectReferenceDefinition>,
+    pub activation_effects: Vec<EffectReferenceDefinition>,
+    pub end_on_activation: bool,
+    pub allow_multiple_instances: bool,
+}
+
+impl GameplayAbilityDefinition {
+    pub fn from_ron(text: &str) -> Result<Self, GameplayDefinitionError> {
+        ron::from_str(text).map_err(|err| GameplayDefinitionError::InvalidRon {
+            message: err.to_string(),
+        })
+    }
+
+    pub fn resolve(
+        &self,
+        resolver: &mut GameplayDefinitionResolver,
+    ) -> Result<GameplayAbility, GameplayDefinitionError> {
+        let tags = AbilityTags::new(
+            resolver.resolve_tags(&self.tags.ability_asset_tags)?,
+            resolver.resolve_tags(&self.tags.cancel_abilities_with_tags)?,
+            resolver.resolve_tags(&self.tags.block_abilities_with_tags)?,
+            resolver.resolve_tags(&self.tags.activation_required_tags)?,
+            resolver.resolve_tags(&self.tags.activation_blocked_tags)?,
+        );
+        let startup_tasks = self
+            .startup_tasks
+            .iter()
+            .map(|task| task.resolve(resolver))
+            .collect::<Result<Vec<_>, _>>()?;
+        let cooldown = self
+            .cooldown
+            .as_ref()
+            .map(|effect| effect.resolve(resolver))
+            .transpose()?;
+        let cost = self
+            .cost
+            .as_ref()
+            .map(|effect| effect.resolve(resolver))
+            .transpose()?;
+        let activation_effects = self
+            .activation_effects
+            .iter()
+            .map(|effect| effect.resolve(resolver))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GameplayAbility::new(
+            tags,
+            startup_tasks,
+            cooldown,
+            cost,
+            activation_effects,
+            self.end_on_activation,
+            self.allow_multiple_instances,
+        ))
+    }
+
+    /// Asset paths of every `Path` effect reference.
+    pub fn get_effect_paths(&self) -> Vec<&str> {
+        let mut paths = Vec::new();
+        let task_effects =
+            self.startup_tasks
+                .iter()
+                .filter_map(|task| match task.get_on_finished() {
+                    AbilityTaskOnFinishedDefinition::ApplyGameplayEffectToTarget { effect } => {
+                        Some(effect)
+                    }
+                    _ => None,
+                });
+        for effect in self
+            .cooldown
+            .iter()
+            .chain(&self.cost)
+            .chain(&self.activation_effects)
+            .chain(task_effects)
+        {
+            effect.collect_effect_paths(&mut paths);
+        }
+        paths
+    }
+}
@@ -0,0 +1,544 @@
+use super::gameplay_definition::{
+    GameplayAbilityDefinition, GameplayDefinitionError, GameplayDefinitionResolver,
+    GameplayEffectDefinition,
+};
+use crate::ability_system::AbilitySystemParams;
+use crate::attributes::AttributeIdManager;
+use crate::curves::CurveTable;
+use crate::gameplay_abilities::GameplayAbility;
+use crate::gameplay_effects::{GameplayEffect, rebuild_active_effects_from_definition};
+use crate::gameplay_tags::GameplayTagManager;
+use crate::unique_names::UniqueNamePool;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext, UntypedAssetId};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use std::sync::Arc;
+
+/// Parsed `.effect.ron` file. The runtime effect is resolved into [`GameplayDefinitions`] once
+/// the tags and attributes it names are registered.
+#[derive(Asset, TypePath, Debug)]
+pub struct GameplayEffectAsset {
+    definition: GameplayEffectDefinition,
+    dependencies: Vec<(String, Handle<GameplayEffectAsset>)>,
+}
+
+impl GameplayEffectAsset {
+    pub fn get_definition(&self) -> &GameplayEffectDefinition {
+        &self.definition
+    }
+
+    /// Edits through `Assets::get_mut` re-resolve the effect like a reloaded file. New `Path`
+    /// references are not loaded; they must already be dependencies of this asset.
+    pub fn get_definition_mut(&mut self) -> &mut GameplayEffectDefinition {
+        &mut self.definition
+    }
+}
+
+/// Parsed `.ability.ron` file, resolved into [`GameplayDefinitions`] like [`GameplayEffectAsset`].
+#[derive(Asset, TypePath, Debug)]
+pub struct GameplayAbilityAsset {
+    definition: GameplayAbilityDefinition,
+    dependencies: Vec<(String, Handle<GameplayEffectAsset>)>,
+}
+
+impl GameplayAbilityAsset {
+    pub fn get_definition(&self) -> &GameplayAbilityDefinition {
+        &self.definition
+    }
+}
+
+async fn read_definition_text(reader: &mut dyn Reader) -> Result<String, GameplayDefinitionError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(|err| GameplayDefinitionError::Io {
+            message: err.to_string(),
+        })?;
+    String::from_utf8(bytes).map_err(|err| GameplayDefinitionError::Io {
+        message: err.to_string(),
+    })
+}
+
+/// `Path` references are asset paths loaded alongside the definition that names them.
+fn load_dependencies(
+    paths: Vec<&str>,
+    load_context: &mut LoadContext,
+) -> Vec<(String, Handle<GameplayEffectAsset>)> {
+    paths
+        .into_iter()
+        .map(|path| (path.to_string(), load_context.load(path.to_string())))
+        .collect()
+}
+
+#[derive(Default, TypePath)]
+pub struct GameplayEffectAssetLoader;
+
+impl AssetLoader for GameplayEffectAssetLoader {
+    type Asset = GameplayEffectAsset;
+    type Settings = ();
+    type Error = GameplayDefinitionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<GameplayEffectAsset, GameplayDefinitionError> {
+        let definition = GameplayEffectDefinition::from_ron(&read_definition_text(reader).await?)?;
+        let dependencies = load_dependencies(definition.get_effect_paths(), load_context);
+        Ok(GameplayEffectAsset {
+            definition,
+            dependencies,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["effect.ron"]
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct GameplayAbilityAssetLoader;
+
+impl AssetLoader for GameplayAbilityAssetLoader {
+    type Asset = GameplayAbilityAsset;
+    type Settings = ();
+    type Error = GameplayDefinitionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<GameplayAbilityAsset, GameplayDefinitionError> {
+        let definition = GameplayAbilityDefinition::from_ron(&read_definition_text(reader).await?)?;
+        let dependencies = load_dependencies(definition.get_effect_paths(), load_context);
+        Ok(GameplayAbilityAsset {
+            definition,
+            dependencies,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ability.ron"]
+    }
+}
+
+/// Fired when a loaded definition asset cannot be resolved, e.g. it names an unregistered tag or
+/// attribute, a referenced effect failed, or its `Path` references form a cycle. The asset itself
+/// stays loaded; the error is also kept in [`GameplayDefinitions::get_error`].
+#[derive(Event, Debug, Clone)]
+pub struct GameplayDefinitionResolveFailedEvent {
+    id: UntypedAssetId,
+    error: GameplayDefinitionError,
+}
+
+impl GameplayDefinitionResolveFailedEvent {
+    pub fn new(id: UntypedAssetId, error: GameplayDefinitionError) -> Self {
+        Self { id, error }
+    }
+
+    pub fn get_id(&self) -> UntypedAssetId {
+        self.id
+    }
+
+    pub fn get_error(&self) -> &GameplayDefinitionError {
+        &self.error
+    }
+}
+
+/// What happens to active effects when the definition they were applied from is reloaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GameplayDefinitionReloadPolicy {
+    /// Active effects keep their old spec; only new applications use the new definition.
+    #[default]
+    KeepActiveSpecs,
+    /// Active effects re-make their spec from the new definition, see
+    /// [`rebuild_active_effects_from_definition`].
+    RebuildActiveSpecs,
+}
+
+/// Runtime effects and abilities resolved from loaded definition assets.
+///
+/// Every asset resolves to a single `Arc`, keyed by its asset path through
+/// [`GameplayEffect::with_def_key`], so effects applied from the same asset stack together, also
+/// across reloads. When an asset is modified it resolves to a new `Arc`, and so does every
+/// definition that references it by path.
+#[derive(Resource, Default)]
+pub struct GameplayDefinitions {
+    effects: HashMap<AssetId<GameplayEffectAsset>, Arc<GameplayEffect>>,
+    abilities: HashMap<AssetId<GameplayAbilityAsset>, Arc<GameplayAbility>>,
+    errors: HashMap<UntypedAssetId, GameplayDefinitionError>,
+    pending_effects: Vec<AssetId<GameplayEffectAsset>>,
+    pending_abilities: Vec<AssetId<GameplayAbilityAsset>>,
+    reload_policy: GameplayDefinitionReloadPolicy,
+    reloaded_effects: Vec<(Arc<GameplayEffect>, Arc<GameplayEffect>)>,
+    reloaded_abilities: Vec<(Arc<GameplayAbility>, Arc<GameplayAbility>)>,
+}
+
+impl GameplayDefinitions {
+    pub fn with_reload_policy(mut self, reload_policy: GameplayDefinitionReloadPolicy) -> Self {
+        self.reload_policy = reload_policy;
+        self
+    }
+
+    pub fn get_reload_policy(&self) -> GameplayDefinitionReloadPolicy {
+        self.reload_policy
+    }
+
+    pub fn set_reload_policy(&mut self, reload_policy: GameplayDefinitionReloadPolicy) {
+        self.reload_policy = reload_policy;
+    }
+
+    pub fn get_effect(
+        &self,
+        id: impl Into<AssetId<GameplayEffectAsset>>,
+    ) -> Option<&Arc<GameplayEffect>> {
+        self.effects.get(&id.into())
+    }
+
+    pub fn get_ability(
+        &self,
+        id: impl Into<AssetId<GameplayAbilityAsset>>,
+    ) -> Option<&Arc<GameplayAbility>> {
+        self.abilities.get(&id.into())
+    }
+
+    /// Why a loaded definition could not be resolved, e.g. an unknown tag name.
+    pub fn get_error(&self, id: impl Into<UntypedAssetId>) -> Option<&GameplayDefinitionError> {
+        self.errors.get(&id.into())
+    }
+
+    /// `true` while a loaded definition waits for a referenced effect to resolve.
+    pub fn is_pending(&self, id: impl Into<UntypedAssetId>) -> bool {
+        let id = id.into();
+        self.pending_effects
+            .iter()
+            .any(|pending| id == pending.untyped())
+            || self
+                .pending_abilities
+                .iter()
+                .any(|pending| id == pending.untyped())
+    }
+
+    fn mark_effect_pending(&mut self, id: AssetId<GameplayEffectAsset>) {
+        if !self.pending_effects.contains(&id) {
+            self.pending_effects.push(id);
+        }
+    }
+
+    fn mark_ability_pending(&mut self, id: AssetId<GameplayAbilityAsset>) {
+        if !self.pending_abilities.contains(&id) {
+            self.pending_abilities.push(id);
+        }
+    }
+
+    /// Stores `effect` and, when it replaces an earlier resolution, queues the pair for
+    /// [`apply_gameplay_definition_reloads_system`]. Returns whether it was a reload.
+    fn insert_effect(&mut self, id: AssetId<GameplayEffectAsset>, effect: GameplayEffect) -> bool {
+        self.errors.remove(&id.untyped());
+        let effect = Arc::new(effect);
+        let Some(old) = self.effects.insert(id, effect.clone()) else {
+            return false;
+        };
+        self.queue_effect_reload(old, effect);
+        true
+    }
+
+    fn insert_ability(&mut self, id: AssetId<GameplayAbilityAsset>, ability: GameplayAbility) {
+        self.errors.remove(&id.untyped());
+        let ability = Arc::new(ability);
+        let Some(old) = self.abilities.insert(id, ability.clone()) else {
+            return;
+        };
+        // Inline effects are re-made with the ability, so pair them up like reloaded assets.
+        let effect_pairs = old
+            .get_cooldown()
+            .zip(ability.get_cooldown())
+            .into_iter()
+            .chain(old.get_cost().zip(ability.get_cost()))
+            .chain(
+                old.get_activation_effects()
+                    .iter()
+                    .zip(ability.get_activation_effects()),
+            )
+            .map(|(old, new)| (old.clone(), new.clone()))
+            .collect::<Vec<_>>();
+        for (old_effect, new_effect) in effect_pairs {
+            self.queue_effect_reload(old_effect, new_effect);
+        }
+        self.reloaded_abilities.push((old, ability));
+    }
+
+    fn queue_effect_reload(&mut self, old: Arc<GameplayEffect>, new: Arc<GameplayEffect>) {
+        if Arc::ptr_eq(&old, &new)
+            || self
+                .reloaded_effects
+                .iter()
+                .any(|(queued, _)| Arc::ptr_eq(queued, &old))
+        {
+            return;
+        }
+        self.reloaded_effects.push((old, new));
+    }
+
+    fn fail(
+        &mut self,
+        id: UntypedAssetId,
+        error: GameplayDefinitionError,
+        commands: &mut Commands,
+    ) {
+        commands.trigger(GameplayDefinitionResolveFailedEvent::new(id, error.clone()));
+        self.errors.insert(id, error);
+    }
+
+    fn remove_effect(&mut self, id: AssetId<GameplayEffectAsset>) {
+        self.effects.remove(&id);
+        self.errors.remove(&id.untyped());
+        self.pending_effects.retain(|pending| *pending != id);
+    }
+
+    fn remove_ability(&mut self, id: AssetId<GameplayAbilityAsset>) {
+        self.abilities.remove(&id);
+        self.errors.remove(&id.untyped());
+        self.pending_abilities.retain(|pending| *pending != id);
+    }
+
+    /// Resolver with every path in `dependencies` bound to its resolved effect, or `None` while
+    /// one of them is still loading.
+    fn dependency_resolver<'a>(
+        &self,
+        dependencies: &[(String, Handle<GameplayEffectAsset>)],
+        asset_server: &AssetServer,
+        mut resolver: GameplayDefinitionResolver<'a>,
+    ) -> Option<Result<GameplayDefinitionResolver<'a>, GameplayDefinitionError>> {
+        for (path, handle) in dependencies {
+            if let Some(effect) = self.effects.get(&handle.id()) {
+                resolver = resolver.with_effect(path.clone(), effect.clone());
+            } else if self.errors.contains_key(&handle.id().untyped())
+                || asset_server.load_state(handle).is_failed()
+            {
+                return Some(Err(GameplayDefinitionError::DependencyFailed {
+                    path: path.clone(),
+                }));
+            } else {
+                return None;
+            }
+        }
+        Some(Ok(resolver))
+    }
+}
+
+#[derive(SystemParam)]
+pub struct GameplayDefinitionResolveParams<'w> {
+    names: ResMut<'w, UniqueNamePool>,
+    tag_manager: Res<'w, GameplayTagManager>,
+    attribute_ids: Res<'w, AttributeIdManager>,
+    curve_table: Option<Res<'w, CurveTable>>,
+}
+
+impl GameplayDefinitionResolveParams<'_> {
+    fn resolver(&mut self) -> GameplayDefinitionResolver<'_> {
+        let resolver = GameplayDefinitionResolver::new(
+            &mut self.names,
+            &self.tag_manager,
+            &self.attribute_ids,
+        );
+        match &self.curve_table {
+            Some(curve_table) => resolver.with_curve_table(curve_table),
+            None => resolver,
+        }
+    }
+}
+
+/// Resolves added and modified definition assets. Definitions that reference effects still
+/// loading are retried on later runs; effects resolve before the abilities that use them.
+/// Failures trigger [`GameplayDefinitionResolveFailedEvent`].
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_gameplay_definitions_system(
+    mut commands: Commands,
+    mut effect_events: MessageReader<AssetEvent<GameplayEffectAsset>>,
+    mut ability_events: MessageReader<AssetEvent<GameplayAbilityAsset>>,
+    effect_assets: Res<Assets<GameplayEffectAsset>>,
+    ability_assets: Res<Assets<GameplayAbilityAsset>>,
+    asset_server: Res<AssetServer>,
+    mut definitions: ResMut<GameplayDefinitions>,
+    mut params: GameplayDefinitionResolveParams,
+) {
+    for event in effect_events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                definitions.mark_effect_pending(id);
+            }
+            AssetEvent::Removed { id } => definitions.remove_effect(id),
+            _ => {}
+        }
+    }
+    for event in ability_events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                definitions.mark_ability_pending(id);
+            }
+            AssetEvent::Removed { id } => definitions.remove_ability(id),
+            _ => {}
+        }
+    }
+
+    // Effects may reference each other, so keep going while any of them resolves.
+    loop {
+        let mut resolved_any = false;
+        for id in std::mem::take(&mut definitions.pending_effects) {
+            let Some(asset) = effect_assets.get(id) else {
+                continue;
+            };
+            let resolved = definitions
+                .dependency_resolver(&asset.dependencies, &asset_server, params.resolver())
+                .map(|resolver| asset.definition.resolve(&mut resolver?));
+            match resolved {
+                Some(Ok(mut effect)) => {
+                    resolved_any = true;
+                    if let Some(path) = asset_server.get_path(id) {
+                        effect = effect.with_def_key(params.names.new_name(&path.to_string()));
+                    }
+                    if definitions.insert_effect(id, effect) {
+                        mark_dependents_pending(
+                            id,
+                            &effect_assets,
+                            &ability_assets,
+                            &mut definitions,
+                        );
+                    }
+                }
+                Some(Err(err)) => {
+                    resolved_any = true;
+                    error!("failed to resolve gameplay effect definition {id}: {err}");
+                    definitions.effects.remove(&id);
+                    definitions.fail(id.untyped(), err, &mut commands);
+                }
+                None => definitions.pending_effects.push(id),
+            }
+        }
+        if !resolved_any {
+            // Effects that wait on themselves through `Path` references can never resolve.
+            resolved_any = fail_dependency_cycles(&effect_assets, &mut definitions, &mut commands);
+        }
+        if !resolved_any || definitions.pending_effects.is_empty() {
+            break;
+        }
+    }
+
+    for id in std::mem::take(&mut definitions.pending_abilities) {
+        let Some(asset) = ability_assets.get(id) else {
+            continue;
+        };
+        let resolved = definitions
+            .dependency_resolver(&asset.dependencies, &asset_server, params.resolver())
+            .map(|resolver| asset.definition.resolve(&mut resolver?));
+        match resolved {
+            Some(Ok(ability)) => definitions.insert_ability(id, ability),
+            Some(Err(err)) => {
+                error!("failed to resolve gameplay ability definition {id}: {err}");
+                definitions.abilities.remove(&id);
+                definitions.fail(id.untyped(), err, &mut commands);
+            }
+            None => definitions.pending_abilities.push(id),
+        }
+    }
+}
+
+/// Fails every pending effect that reaches itself through the `Path` references of pending
+/// effects, with `DependencyFailed` naming its first reference into the cycle. Returns whether any
+/// effect failed.
+fn fail_dependency_cycles(
+    effect_assets: &Assets<GameplayEffectAsset>,
+    definitions: &mut GameplayDefinitions,
+    commands: &mut Commands,
+) -> bool {
+    let pending = definitions.pending_effects.clone();
+    let pending_dependencies = |id: AssetId<GameplayEffectAsset>| {
+        effect_assets
+            .get(id)
+            .into_iter()
+            .flat_map(|asset| asset.dependencies.iter())
+            .filter(|(_, handle)| pending.contains(&handle.id()))
+    };
+    let reaches = |from: AssetId<GameplayEffectAsset>, to: AssetId<GameplayEffectAsset>| {
+        let mut visited = vec![from];
+        let mut stack = vec![from];
+        while let Some(id) = stack.pop() {
+            for (_, handle) in pending_dependencies(id) {
+                if handle.id() == to {
+                    return true;
+                }
+                if !visited.contains(&handle.id()) {
+                    visited.push(handle.id());
+                    stack.push(handle.id());
+                }
+            }
+        }
+        false
+    };
+
+    let mut failed_any = false;
+    for &id in &pending {
+        let Some((path, _)) = pending_dependencies(id)
+            .find(|(_, handle)| handle.id() == id || reaches(handle.id(), id))
+        else {
+            continue;
+        };
+        let err = GameplayDefinitionError::DependencyFailed { path: path.clone() };
+        error!("failed to resolve gameplay effect definition {id}: {err} (dependency cycle)");
+        definitions.pending_effects.retain(|pending| *pending != id);
+        definitions.effects.remove(&id);
+        definitions.fail(id.untyped(), err, commands);
+        failed_any = true;
+    }
+    failed_any
+}
+
+/// Re-resolves the definitions that reference the reloaded effect `id` by path, so they pick up
+/// its new `Arc`.
+fn mark_dependents_pending(
+    id: AssetId<GameplayEffectAsset>,
+    effect_assets: &Assets<GameplayEffectAsset>,
+    ability_assets: &Assets<GameplayAbilityAsset>,
+    definitions: &mut GameplayDefinitions,
+) {
+    let references = |dependencies: &[(String, Handle<GameplayEffectAsset>)]| {
+        dependencies.iter().any(|(_, handle)| handle.id() == id)
+    };
+    for (dependent, asset) in effect_assets.iter() {
+        if references(&asset.dependencies) {
+            definitions.mark_effect_pending(dependent);
+        }
+    }
+    for (dependent, asset) in ability_assets.iter() {
+        if references(&asset.dependencies) {
+            definitions.mark_ability_pending(dependent);
+        }
+    }
+}
+
+/// Hands definitions replaced by [`resolve_gameplay_definitions_system`] to the runtime: granted
+/// abilities switch to the new definition, and active effects follow the
+/// [`GameplayDefinitionReloadPolicy`].
+pub fn apply_gameplay_definition_reloads_system(
+    mut definitions: ResMut<GameplayDefinitions>,
+    mut params: AbilitySystemParams,
+) {
+    for (old, new) in std::mem::take(&mut definitions.reloaded_abilities) {
+        for mut ability_system in params.asc_query.iter_mut() {
+            ability_system.replace_ability(&old, &new, &params.tag_manager);
+        }
+    }
+
+    let reloaded_effects = std::mem::take(&mut definitions.reloaded_effects);
+    if definitions.reload_policy == GameplayDefinitionReloadPolicy::RebuildActiveSpecs {
+        for (old, new) in &reloaded_effects {
+            rebuild_active_effects_from_definition(old, new, &mut params);
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use crate::ability_system::AbilityActivationQueue;
+use crate::ability_system::{AbilityActivationQueue, GameplayTimeScale, get_gameplay_time_ticks};
 use crate::gameplay_abilities::{
     AbilityActivationStatus, AbilitySpecHandle, ActiveAbilityHandle, ActiveGameplayAbility,
 };
 use crate::gameplay_effects::{EffectPayload, GameplayEffect, GameplayEffectApplicationQueue};
+use crate::modifiers::SetByCallerKey;
 use crate::unique_names::UniqueName;
 use bevy::prelude::*;
 use std::sync::Arc;
@@ -184,6 +185,7 @@ pub struct AbilityTask {
     active_ability: ActiveAbilityHandle,
     kind: AbilityTaskKind,
     on_finished: AbilityTaskOnFinished,
+    set_by_caller_magnitudes: Vec<(SetByCallerKey, f64)>,
 }
 
 impl AbilityTask {
@@ -195,6 +197,7 @@ impl AbilityTask {
             active_ability,
             kind: AbilityTaskKind::Instant,
             on_finished,
+            set_by_caller_magnitudes: Vec::new(),
         }
     }
 
@@ -209,9 +212,37 @@ impl AbilityTask {
                 remaining_ticks: ticks,
             },
             on_finished,
+            set_by_caller_magnitudes: Vec::new(),
         }
     }
 
+    pub fn with_set_by_caller_magnitude(
+        mut self,
+        key: impl Into<SetByCallerKey>,
+        value: f64,
+    ) -> Self {
+        self.set_set_by_caller_magnitude(key, value);
+        self
+    }
+
+    /// Copied onto the payload of the effect this task applies when it finishes, so gameplay code
+    /// can update it while the task waits, e.g. the charge of a held shot.
+    pub fn set_set_by_caller_magnitude(&mut self, key: impl Into<SetByCallerKey>, value: f64) {
+        let key = key.into();
+        match self
+            .set_by_caller_magnitudes
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            Some((_, existing_value)) => *existing_value = value,
+            None => self.set_by_caller_magnitudes.push((key, value)),
+        }
+    }
+
+    pub fn get_set_by_caller_magnitudes(&self) -> &[(SetByCallerKey, f64)] {
+        &self.set_by_caller_magnitudes
+    }
+
     pub fn get_active_ability(&self) -> ActiveAbilityHandle {
         self.active_ability
     }
@@ -243,6 +274,7 @@ pub fn tick_ability_tasks_system(
     mut active_ability_query: Query<&mut ActiveGameplayAbility>,
     mut activation_queue: ResMut<AbilityActivationQueue>,
     mut effect_queue: ResMut<GameplayEffectApplicationQueue>,
+    time_query: Query<&GameplayTimeScale>,
 ) {
     for (task_entity, mut task) in task_query.iter_mut() {
         let Ok(active_ability) = active_ability_query.get(task.get_active_ability()) else {
@@ -252,13 +284,16 @@ pub fn tick_ability_tasks_system(
 
         let active_status = active_ability.get_status();
         let active_context = active_ability.get_activation_context().clone();
+        let ticks = get_gameplay_time_ticks(&time_query, active_ability.get_source());
 
         if !matches!(active_status, AbilityActivationStatus::Active) {
             commands.entity(task_entity).despawn();
             continue;
         }
 
-        if !task.tick() {
+        // Ticks left over once the task finishes are dropped: tasks of an ability run side by
+        // side rather than in sequence, so there is no following task to hand them to.
+        if !(0..ticks).any(|_| task.tick()) {
             continue;
         }
 
@@ -308,8 +343,11 @@ pub fn tick_ability_tasks_system(
                 effect,
                 level,
             } => {
-                let payload =
+                let mut payload =
                     effect_payload_from_activation_context(source, level, &active_context);
+                for (key, value) in task.get_set_by_caller_magnitudes() {
+                    payload.set_set_by_caller_magnitude(*key, *value);
+                }
                 effect_queue.push_application(target, effect, payload);
             }
         }
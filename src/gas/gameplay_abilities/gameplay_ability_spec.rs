@@ -20,6 +20,7 @@ pub struct GameplayAbilitySpec {
     level: u32,
     input_id: Option<u16>,
     active_count: u32,
+    remove_on_end: bool,
 }
 
 impl GameplayAbilitySpec {
@@ -35,6 +36,7 @@ impl GameplayAbilitySpec {
             level,
             input_id,
             active_count: 0,
+            remove_on_end: false,
         }
     }
 
@@ -46,6 +48,10 @@ impl GameplayAbilitySpec {
         &self.ability
     }
 
+    pub(crate) fn set_ability(&mut self, ability: Arc<GameplayAbility>) {
+        self.ability = ability;
+    }
+
     pub fn get_level(&self) -> u32 {
         self.level
     }
@@ -58,6 +64,16 @@ impl GameplayAbilitySpec {
         self.active_count
     }
 
+    /// `true` once the spec is waiting for its running instances to end before it is removed;
+    /// it can no longer be activated.
+    pub fn is_remove_on_end(&self) -> bool {
+        self.remove_on_end
+    }
+
+    pub(crate) fn set_remove_on_end(&mut self) {
+        self.remove_on_end = true;
+    }
+
     pub fn increment_active_count(&mut self) {
         self.active_count = self.active_count.saturating_add(1);
     }
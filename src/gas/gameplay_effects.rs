@@ -1,9 +1,13 @@
 mod active_gameplay_effect;
 mod gameplay_effect;
 mod gameplay_effect_application_queue;
+mod gameplay_effect_events;
+mod gameplay_effect_execution;
 mod gameplay_effect_spec;
 
 pub use active_gameplay_effect::*;
 pub use gameplay_effect::*;
 pub use gameplay_effect_application_queue::*;
+pub use gameplay_effect_events::*;
+pub use gameplay_effect_execution::*;
 pub use gameplay_effect_spec::*;
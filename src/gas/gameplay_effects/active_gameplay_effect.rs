@@ -1,16 +1,38 @@
+use super::gameplay_effect::EffectContext;
 use super::gameplay_effect::{
-    EffectPayload, GameplayEffect, StackDurationPolicy, StackExpirationPolicy,
-    StackMagnitudePolicy, StackOverflowPolicy, StackPeriodPolicy, StackingType,
+    EffectPayload, GameplayEffect, GrantedAbilityRemovalPolicy, StackDurationPolicy,
+    StackExpirationPolicy, StackOverflowPolicy, StackPeriodPolicy, StackingType, ticks_to_seconds,
+};
+use super::gameplay_effect_application_queue::{
+    GameplayEffectApplicationQueue, GameplayEffectApplicationRequest,
+};
+use super::gameplay_effect_events::{
+    GameplayEffectLifecycle, GameplayEffectLifecycleEvent, GameplayEffectRejectedEvent,
+    GameplayEffectRejectionReason,
+};
+use super::gameplay_effect_execution::{
+    GameplayEffectExecutedEvent, GameplayEffectExecutionOutput,
 };
 use super::gameplay_effect_spec::{EffectDurationTicksSpec, GameplayEffectSpec};
-use crate::ability_system::AbilitySystemParams;
-use crate::attributes::AttributeSet;
+use crate::Random;
+use crate::ability_system::{
+    AbilitySystemComponent, AbilitySystemParams, GameplayTimeScale, get_gameplay_time_ticks,
+    remove_granted_abilities,
+};
+use crate::attributes::{
+    AttributeBreakdown, AttributeChangeCause, AttributeId, AttributeSet, AttributeSetSnapshot,
+};
+use crate::gameplay_abilities::AbilitySpecHandle;
 use crate::gameplay_tags::{
     GameplayTag, GameplayTagContainer, GameplayTagManager, tag_bits_from_tags_with_manager,
 };
+use crate::modifiers::SetByCallerKey;
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use std::error::Error;
+use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub type ActiveEffectHandle = Entity;
 
@@ -66,18 +88,27 @@ pub struct ActiveGameplayEffect {
     spec: GameplayEffectSpec,
     source: Entity,
     target: Entity,
+    payload: EffectPayload,
     stack_count: u32,
     inhibited: bool,
+    active_modifiers: Vec<bool>,
+    granted_abilities: Vec<(AbilitySpecHandle, GrantedAbilityRemovalPolicy)>,
 }
 
 impl ActiveGameplayEffect {
-    pub fn new(spec: GameplayEffectSpec, source: Entity, target: Entity) -> Self {
+    /// `payload` is the payload of the application that creates this effect; its source becomes
+    /// the effect source.
+    pub fn new(spec: GameplayEffectSpec, target: Entity, payload: EffectPayload) -> Self {
+        let active_modifiers = vec![true; spec.get_modifier_specs().len()];
         Self {
             spec,
-            source,
+            source: payload.get_source(),
             target,
+            payload,
             stack_count: 1,
             inhibited: false,
+            active_modifiers,
+            granted_abilities: Vec::new(),
         }
     }
 
@@ -93,6 +124,11 @@ impl ActiveGameplayEffect {
         self.target
     }
 
+    /// Payload of the application that created this effect.
+    pub fn get_payload(&self) -> &EffectPayload {
+        &self.payload
+    }
+
     pub fn get_stack_count(&self) -> u32 {
         self.stack_count
     }
@@ -108,6 +144,21 @@ impl ActiveGameplayEffect {
     pub fn set_inhibited(&mut self, inhibited: bool) {
         self.inhibited = inhibited;
     }
+
+    /// Per modifier spec, whether its source/target tag requirements currently pass.
+    pub fn get_active_modifiers(&self) -> &[bool] {
+        &self.active_modifiers
+    }
+
+    pub fn set_active_modifiers(&mut self, active_modifiers: Vec<bool>) {
+        self.active_modifiers = active_modifiers;
+    }
+
+    /// Ability specs given to the target when this effect was created, in the order of
+    /// `GameplayEffect::get_granted_abilities`, with the policy applied when it is removed.
+    pub fn get_granted_abilities(&self) -> &[(AbilitySpecHandle, GrantedAbilityRemovalPolicy)] {
+        &self.granted_abilities
+    }
 }
 
 impl GameplayEffectApplicationPlan {
@@ -123,6 +174,115 @@ impl GameplayEffectApplicationPlan {
 #[derive(Component)]
 pub struct ActiveEffectDurationTicks {
     remain_ticks: u32,
+    total_ticks: u32,
+    /// Remaining ticks of each stack under `StackDurationPolicy::IndependentPerStack`, soonest
+    /// first; empty otherwise.
+    stack_remain_ticks: Vec<u32>,
+}
+
+impl ActiveEffectDurationTicks {
+    fn new(remain_ticks: u32, policy: StackDurationPolicy) -> Self {
+        let stack_remain_ticks = match policy {
+            StackDurationPolicy::IndependentPerStack => vec![remain_ticks],
+            _ => Vec::new(),
+        };
+        Self {
+            remain_ticks,
+            total_ticks: remain_ticks,
+            stack_remain_ticks,
+        }
+    }
+
+    /// Ticks until the whole effect expires; with independent stacks, until the last stack does.
+    pub fn get_remain_ticks(&self) -> u32 {
+        self.remain_ticks
+    }
+
+    /// Remaining time at `fixed_timestep`, always a whole number of ticks.
+    pub fn get_remain_seconds(&self, fixed_timestep: Duration) -> f64 {
+        ticks_to_seconds(self.remain_ticks, fixed_timestep)
+    }
+
+    /// Duration the timer was last started or refreshed with, raised when an adjustment extends
+    /// the remaining time past it, so `remain / total` stays a valid progress fraction.
+    pub fn get_total_ticks(&self) -> u32 {
+        self.total_ticks
+    }
+
+    pub fn get_total_seconds(&self, fixed_timestep: Duration) -> f64 {
+        ticks_to_seconds(self.total_ticks, fixed_timestep)
+    }
+
+    pub fn has_independent_stacks(&self) -> bool {
+        !self.stack_remain_ticks.is_empty()
+    }
+
+    /// Remaining ticks of each stack, soonest to expire first. Empty unless the effect uses
+    /// `StackDurationPolicy::IndependentPerStack`.
+    pub fn get_stack_remain_ticks(&self) -> &[u32] {
+        &self.stack_remain_ticks
+    }
+
+    fn add_stack(&mut self, remain_ticks: u32) {
+        let index = self
+            .stack_remain_ticks
+            .partition_point(|&ticks| ticks <= remain_ticks);
+        self.stack_remain_ticks.insert(index, remain_ticks);
+        self.remain_ticks = self.remain_ticks.max(remain_ticks);
+        self.total_ticks = self.total_ticks.max(remain_ticks);
+    }
+
+    fn refresh(&mut self, remain_ticks: u32) {
+        self.remain_ticks = remain_ticks;
+        self.total_ticks = remain_ticks;
+    }
+
+    /// Applies `adjustment` to the effect timer and every per-stack timer. A timer adjusted to zero
+    /// expires on the next duration tick.
+    fn adjust(&mut self, adjustment: EffectDurationAdjustment) {
+        self.remain_ticks = adjustment.apply(self.remain_ticks);
+        for ticks in &mut self.stack_remain_ticks {
+            *ticks = adjustment.apply(*ticks);
+        }
+        self.total_ticks = self.total_ticks.max(self.remain_ticks);
+    }
+
+    /// Drops the stacks closest to expiring until at most `stack_count` are left.
+    fn truncate_stacks(&mut self, stack_count: u32) {
+        let excess = self
+            .stack_remain_ticks
+            .len()
+            .saturating_sub(stack_count as usize);
+        self.stack_remain_ticks.drain(..excess);
+    }
+
+    /// Caps every timer at `max_ticks` and gives each of `stack_count` stacks its own timer under
+    /// `IndependentPerStack`, or drops the per-stack timers under any other policy.
+    fn fit_to(&mut self, max_ticks: u32, stack_count: u32, policy: StackDurationPolicy) {
+        self.remain_ticks = self.remain_ticks.min(max_ticks);
+        self.total_ticks = max_ticks;
+        if !matches!(policy, StackDurationPolicy::IndependentPerStack) {
+            self.stack_remain_ticks.clear();
+            return;
+        }
+        for ticks in &mut self.stack_remain_ticks {
+            *ticks = (*ticks).min(max_ticks);
+        }
+        self.truncate_stacks(stack_count);
+        self.stack_remain_ticks
+            .resize(stack_count as usize, self.remain_ticks);
+    }
+
+    /// Advances the timer by one tick and returns how many independent stacks expired.
+    fn tick(&mut self) -> u32 {
+        self.remain_ticks = self.remain_ticks.saturating_sub(1);
+        for ticks in &mut self.stack_remain_ticks {
+            *ticks = ticks.saturating_sub(1);
+        }
+        let expired = self.stack_remain_ticks.partition_point(|&ticks| ticks == 0);
+        self.stack_remain_ticks.drain(..expired);
+        expired as u32
+    }
 }
 
 #[derive(Component)]
@@ -131,12 +291,67 @@ pub struct ActiveEffectPeriodTicks {
     current_tick: u32,
 }
 
+impl ActiveEffectPeriodTicks {
+    pub fn get_period_ticks(&self) -> u32 {
+        self.period_ticks
+    }
+
+    /// Ticks elapsed since the last periodic execution.
+    pub fn get_current_tick(&self) -> u32 {
+        self.current_tick
+    }
+
+    /// Ticks until the next periodic execution.
+    pub fn get_remain_ticks(&self) -> u32 {
+        self.period_ticks.saturating_sub(self.current_tick)
+    }
+
+    pub fn get_remain_seconds(&self, fixed_timestep: Duration) -> f64 {
+        ticks_to_seconds(self.get_remain_ticks(), fixed_timestep)
+    }
+
+    /// Fraction of the current period that has elapsed, from `0.0` up to `1.0`.
+    pub fn get_progress(&self) -> f64 {
+        if self.period_ticks == 0 {
+            return 0.0;
+        }
+        self.current_tick as f64 / self.period_ticks as f64
+    }
+}
+
+/// Change to the remaining duration of an active effect, e.g. "extend every buff by 3 seconds"
+/// is `Add(seconds_to_ticks(3.0, fixed_timestep) as i64)` and "halve stun durations" is
+/// `Scale(0.5)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EffectDurationAdjustment {
+    Set(u32),
+    /// Added ticks, negative to shorten; the result saturates at zero.
+    Add(i64),
+    /// Multiplies the remaining ticks, rounded to the nearest tick; negative scales count as zero.
+    Scale(f64),
+}
+
+impl EffectDurationAdjustment {
+    pub fn apply(&self, remain_ticks: u32) -> u32 {
+        match *self {
+            EffectDurationAdjustment::Set(ticks) => ticks,
+            EffectDurationAdjustment::Add(ticks) => {
+                (remain_ticks as i64 + ticks).clamp(0, u32::MAX as i64) as u32
+            }
+            EffectDurationAdjustment::Scale(scale) => {
+                (remain_ticks as f64 * scale.max(0.0)).round() as u32
+            }
+        }
+    }
+}
+
 pub struct GameplayEffectApplicationPlan {
     source: Entity,
     target: Entity,
     spec: GameplayEffectSpec,
     removed_effects: Vec<ActiveEffectHandle>,
     kind: GameplayEffectApplicationKind,
+    payload: EffectPayload,
 }
 
 enum GameplayEffectApplicationKind {
@@ -146,34 +361,213 @@ enum GameplayEffectApplicationKind {
         new_stack_count: u32,
     },
     CreateActive,
+    /// Application at the stack limit of an effect with overflow effects or
+    /// `clear_stacks_on_overflow`.
+    Overflow {
+        handle: ActiveEffectHandle,
+        stack_count: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectApplicationError {
+    MissingAttributeSet {
+        target: Entity,
+    },
+    MissingGameplayTagContainer {
+        target: Entity,
+    },
+    ProbabilityRollFailed,
+    BlockedBySourceTags {
+        source: Entity,
+    },
+    BlockedByTargetTags {
+        target: Entity,
+    },
+    /// `query_index` indexes the granted application immunity list of the blocking effect.
+    BlockedByImmunity {
+        handle: ActiveEffectHandle,
+        query_index: usize,
+    },
+    StackLimitReached {
+        handle: ActiveEffectHandle,
+        stack_limit: u32,
+    },
+    InvalidSpec {
+        reason: &'static str,
+    },
+    /// A set-by-caller magnitude without fallback has no value in the payload.
+    MissingSetByCallerMagnitude {
+        key: SetByCallerKey,
+    },
+    /// The effect grants abilities and `target` has no `AbilitySystemComponent`.
+    MissingAbilitySystemComponent {
+        target: Entity,
+    },
+    /// A cost would drop `attribute` of `target` below zero.
+    InsufficientAttribute {
+        target: Entity,
+        attribute: AttributeId,
+    },
 }
 
+impl EffectApplicationError {
+    /// Reason reported by [`GameplayEffectRejectedEvent`], for rejections decided before any change.
+    pub fn get_rejection_reason(&self) -> Option<GameplayEffectRejectionReason> {
+        match self {
+            EffectApplicationError::ProbabilityRollFailed => {
+                Some(GameplayEffectRejectionReason::Probability)
+            }
+            EffectApplicationError::BlockedBySourceTags { .. } => {
+                Some(GameplayEffectRejectionReason::SourceRequirements)
+            }
+            EffectApplicationError::BlockedByTargetTags { .. } => {
+                Some(GameplayEffectRejectionReason::TargetRequirements)
+            }
+            EffectApplicationError::BlockedByImmunity { .. } => {
+                Some(GameplayEffectRejectionReason::Immunity)
+            }
+            EffectApplicationError::StackLimitReached { .. } => {
+                Some(GameplayEffectRejectionReason::StackOverflow)
+            }
+            _ => None,
+        }
+    }
+
+    /// The active effect responsible for the error, if any.
+    pub fn get_handle(&self) -> Option<ActiveEffectHandle> {
+        match self {
+            EffectApplicationError::BlockedByImmunity { handle, .. }
+            | EffectApplicationError::StackLimitReached { handle, .. } => Some(*handle),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for EffectApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EffectApplicationError::MissingAttributeSet { target } => write!(
+                f,
+                "effect application failed: target entity {target:?} has no AttributeSet"
+            ),
+            EffectApplicationError::MissingGameplayTagContainer { target } => write!(
+                f,
+                "effect application failed: target entity {target:?} has no GameplayTagContainer"
+            ),
+            EffectApplicationError::ProbabilityRollFailed => {
+                write!(f, "effect application failed: probability roll failed")
+            }
+            EffectApplicationError::BlockedBySourceTags { source } => write!(
+                f,
+                "effect application failed: source entity {source:?} does not meet application tag requirements"
+            ),
+            EffectApplicationError::BlockedByTargetTags { target } => write!(
+                f,
+                "effect application failed: target entity {target:?} does not meet application tag requirements"
+            ),
+            EffectApplicationError::BlockedByImmunity {
+                handle,
+                query_index,
+            } => write!(
+                f,
+                "effect application failed: blocked by immunity query {query_index} of active effect {handle:?}"
+            ),
+            EffectApplicationError::StackLimitReached {
+                handle,
+                stack_limit,
+            } => write!(
+                f,
+                "effect application failed: active effect {handle:?} reached stack limit {stack_limit}"
+            ),
+            EffectApplicationError::InvalidSpec { reason } => {
+                write!(f, "effect application failed: invalid spec, {reason}")
+            }
+            EffectApplicationError::MissingSetByCallerMagnitude { key } => write!(
+                f,
+                "effect application failed: payload has no set-by-caller magnitude for {key}"
+            ),
+            EffectApplicationError::MissingAbilitySystemComponent { target } => write!(
+                f,
+                "effect application failed: target entity {target:?} has no AbilitySystemComponent to grant abilities to"
+            ),
+            EffectApplicationError::InsufficientAttribute { target, attribute } => write!(
+                f,
+                "effect application failed: target entity {target:?} cannot afford attribute {}",
+                attribute.to_index()
+            ),
+        }
+    }
+}
+
+impl Error for EffectApplicationError {}
+
+/// Checks everything that can be decided before changing the world and freezes the spec.
+///
+/// Rejections with a [`GameplayEffectRejectionReason`] also trigger [`GameplayEffectRejectedEvent`].
 pub fn prepare_gameplay_effect(
     target: Entity,
     effect_def: &Arc<GameplayEffect>,
     params: &mut AbilitySystemParams,
     payload: &EffectPayload,
-) -> Option<GameplayEffectApplicationPlan> {
+) -> Result<GameplayEffectApplicationPlan, EffectApplicationError> {
+    let result = prepare_gameplay_effect_plan(target, effect_def, params, payload);
+    if let Err(err) = &result {
+        trigger_rejected_event(&mut params.commands, target, err, payload);
+    }
+    result
+}
+
+fn trigger_rejected_event(
+    commands: &mut Commands,
+    target: Entity,
+    err: &EffectApplicationError,
+    payload: &EffectPayload,
+) {
+    if let Some(reason) = err.get_rejection_reason() {
+        commands.trigger(GameplayEffectRejectedEvent::new(
+            target,
+            err.get_handle(),
+            reason,
+            payload.clone(),
+        ));
+    }
+}
+
+fn prepare_gameplay_effect_plan(
+    target: Entity,
+    effect_def: &Arc<GameplayEffect>,
+    params: &mut AbilitySystemParams,
+    payload: &EffectPayload,
+) -> Result<GameplayEffectApplicationPlan, EffectApplicationError> {
     let source = payload.get_source();
 
     let probability = effect_def.get_probability_to_apply();
     if probability < 1.0 && !params.random_gen.random_bool(probability) {
-        return None;
+        return Err(EffectApplicationError::ProbabilityRollFailed);
     }
 
     let incoming_tags = effect_def.get_tags();
-    if !passes_application_requirements(source, target, incoming_tags, params) {
-        return None;
+    check_application_requirements(payload, target, incoming_tags, params)?;
+
+    if let Some((handle, query_index)) =
+        find_blocking_application_immunity(payload, target, incoming_tags, params)
+    {
+        return Err(EffectApplicationError::BlockedByImmunity {
+            handle,
+            query_index,
+        });
     }
 
-    if is_blocked_by_application_immunity(source, target, incoming_tags, params) {
-        return None;
+    if let Some(key) = effect_def.find_missing_set_by_caller(payload) {
+        return Err(EffectApplicationError::MissingSetByCallerMagnitude { key });
     }
 
     let spec = {
         let context = crate::gameplay_effects::EffectContext {
             target: Some(target),
             payload,
+            fixed_timestep: params.fixed_time.timestep(),
             attr_set_query: &params.attr_set_query.as_readonly(),
             tag_container_query: &params.tag_container_query.as_readonly(),
             asc_query: &params.asc_query.as_readonly(),
@@ -184,10 +578,17 @@ pub fn prepare_gameplay_effect(
 
     let duration_spec = spec.get_duration_spec();
     if matches!(duration_spec, EffectDurationTicksSpec::DurationTicks(0)) {
-        return None;
+        return Err(EffectApplicationError::InvalidSpec {
+            reason: "duration resolved to zero ticks",
+        });
+    }
+    if !effect_def.get_executions().is_empty() && !executes_instantly(&spec) {
+        return Err(EffectApplicationError::InvalidSpec {
+            reason: "executions need an instant or periodic effect",
+        });
     }
 
-    let has_modifiers = !spec.get_modifier_specs().is_empty();
+    let has_modifiers = has_any_modifiers(&spec);
     let needs_attribute_set = has_modifiers
         && (duration_spec.is_instant()
             || spec.get_period_spec().is_none()
@@ -195,12 +596,18 @@ pub fn prepare_gameplay_effect(
                 period.get_execute_on_applied() || period.get_period_ticks() > 0
             }));
     if needs_attribute_set && params.attr_set_query.get(target).is_err() {
-        return None;
+        return Err(EffectApplicationError::MissingAttributeSet { target });
     }
 
     let grants_tags = !spec.get_def_tags().get_granted_tags().is_empty();
     if grants_tags && params.tag_container_query.get(target).is_err() {
-        return None;
+        return Err(EffectApplicationError::MissingGameplayTagContainer { target });
+    }
+
+    let grants_abilities =
+        !effect_def.get_granted_abilities().is_empty() && !duration_spec.is_instant();
+    if grants_abilities && params.asc_query.get(target).is_err() {
+        return Err(EffectApplicationError::MissingAbilitySystemComponent { target });
     }
 
     let removed_effects = collect_active_effects_with_tags_for_params(
@@ -222,10 +629,37 @@ pub fn prepare_gameplay_effect(
         let stacking_policy = spec.get_stacking_policy();
         let limit = stacking_policy.get_stack_limit();
         if limit != 0 && stack_count >= limit {
+            if !effect_def.get_overflow_effects().is_empty()
+                || stacking_policy.is_clear_stacks_on_overflow()
+            {
+                // A denied overflow application only queues the overflow effects, so it must not
+                // remove the effects matched by `remove_effects_with_tags`.
+                let denied = !stacking_policy.is_clear_stacks_on_overflow()
+                    && matches!(
+                        stacking_policy.get_overflow_policy(),
+                        StackOverflowPolicy::RejectApplication
+                    );
+                return Ok(GameplayEffectApplicationPlan {
+                    source,
+                    target,
+                    spec,
+                    removed_effects: if denied { Vec::new() } else { removed_effects },
+                    kind: GameplayEffectApplicationKind::Overflow {
+                        handle,
+                        stack_count,
+                    },
+                    payload: payload.clone(),
+                });
+            }
             match stacking_policy.get_overflow_policy() {
-                StackOverflowPolicy::RejectApplication => return None,
+                StackOverflowPolicy::RejectApplication => {
+                    return Err(EffectApplicationError::StackLimitReached {
+                        handle,
+                        stack_limit: limit,
+                    });
+                }
                 StackOverflowPolicy::RefreshDuration => {
-                    return Some(GameplayEffectApplicationPlan {
+                    return Ok(GameplayEffectApplicationPlan {
                         source,
                         target,
                         spec,
@@ -234,12 +668,13 @@ pub fn prepare_gameplay_effect(
                             handle,
                             new_stack_count: stack_count,
                         },
+                        payload: payload.clone(),
                     });
                 }
             }
         }
 
-        return Some(GameplayEffectApplicationPlan {
+        return Ok(GameplayEffectApplicationPlan {
             source,
             target,
             spec,
@@ -248,6 +683,7 @@ pub fn prepare_gameplay_effect(
                 handle,
                 new_stack_count: stack_count.saturating_add(1),
             },
+            payload: payload.clone(),
         });
     }
 
@@ -257,19 +693,20 @@ pub fn prepare_gameplay_effect(
         GameplayEffectApplicationKind::CreateActive
     };
 
-    Some(GameplayEffectApplicationPlan {
+    Ok(GameplayEffectApplicationPlan {
         source,
         target,
         spec,
         removed_effects,
         kind,
+        payload: payload.clone(),
     })
 }
 
 pub fn execute_gameplay_effect_plan(
     plan: GameplayEffectApplicationPlan,
     params: &mut AbilitySystemParams,
-) -> bool {
+) -> Result<(), EffectApplicationError> {
     remove_collected_active_effects_for_params(
         &plan.removed_effects,
         &mut params.active_effect_query,
@@ -280,14 +717,132 @@ pub fn execute_gameplay_effect_plan(
         &mut params.active_effect_target_index,
     );
 
-    match plan.kind {
+    let result = match plan.kind {
         GameplayEffectApplicationKind::Instant => execute_instant_effect(&plan, params),
         GameplayEffectApplicationKind::StackExisting {
             handle,
             new_stack_count,
         } => execute_stack_existing_effect(&plan, handle, new_stack_count, params),
         GameplayEffectApplicationKind::CreateActive => execute_new_active_effect(&plan, params),
+        GameplayEffectApplicationKind::Overflow {
+            handle,
+            stack_count,
+        } => {
+            // A denied overflow application did not apply, so it has no conditional effects.
+            return execute_stack_overflow(&plan, handle, stack_count, params);
+        }
+    };
+    if result.is_ok() {
+        queue_conditional_effects(&plan, params);
+    }
+    result
+}
+
+/// Queues the overflow effects, then clears the stacks, refreshes the existing effect with
+/// `StackOverflowPolicy::RefreshDuration`, or denies the application.
+fn execute_stack_overflow(
+    plan: &GameplayEffectApplicationPlan,
+    handle: ActiveEffectHandle,
+    stack_count: u32,
+    params: &mut AbilitySystemParams,
+) -> Result<(), EffectApplicationError> {
+    let overflow_effects = plan.spec.get_def().get_overflow_effects().to_vec();
+    queue_chained_effects(plan, overflow_effects, params);
+    params.commands.trigger(GameplayEffectLifecycleEvent::new(
+        plan.target,
+        Some(handle),
+        GameplayEffectLifecycle::Overflowed,
+        plan.payload.clone(),
+    ));
+
+    let stacking_policy = plan.spec.get_stacking_policy();
+    if stacking_policy.is_clear_stacks_on_overflow() {
+        remove_active_effect(handle, params);
+        return Ok(());
+    }
+    match stacking_policy.get_overflow_policy() {
+        // The overflow effects still apply, but the application itself is denied.
+        StackOverflowPolicy::RejectApplication => {
+            let err = EffectApplicationError::StackLimitReached {
+                handle,
+                stack_limit: stacking_policy.get_stack_limit(),
+            };
+            trigger_rejected_event(&mut params.commands, plan.target, &err, &plan.payload);
+            Err(err)
+        }
+        StackOverflowPolicy::RefreshDuration => {
+            execute_stack_existing_effect(plan, handle, stack_count, params)?;
+            queue_conditional_effects(plan, params);
+            Ok(())
+        }
+    }
+}
+
+fn trigger_lifecycle_event(
+    commands: &mut Commands,
+    handle: ActiveEffectHandle,
+    effect: &ActiveGameplayEffect,
+    lifecycle: GameplayEffectLifecycle,
+) {
+    commands.trigger(GameplayEffectLifecycleEvent::new(
+        effect.get_target(),
+        Some(handle),
+        lifecycle,
+        effect.get_payload().clone(),
+    ));
+}
+
+fn queue_conditional_effects(
+    plan: &GameplayEffectApplicationPlan,
+    params: &mut AbilitySystemParams,
+) {
+    if plan.spec.get_def().get_conditional_effects().is_empty() {
+        return;
+    }
+
+    let source_tags = payload_source_tags(&plan.payload, &params.tag_container_query);
+    let effects: Vec<_> = plan
+        .spec
+        .get_def()
+        .get_conditional_effects()
+        .iter()
+        .filter(|conditional| conditional.get_source_tags().passes(source_tags))
+        .map(|conditional| conditional.get_effect().clone())
+        .collect();
+    queue_chained_effects(plan, effects, params);
+}
+
+/// Queues `effects` on the plan's target with the next payload in the effect chain.
+fn queue_chained_effects(
+    plan: &GameplayEffectApplicationPlan,
+    effects: Vec<Arc<GameplayEffect>>,
+    params: &mut AbilitySystemParams,
+) {
+    if effects.is_empty() {
+        return;
     }
+    let next_payload = match plan.payload.next_in_effect_chain() {
+        Ok(next_payload) => next_payload,
+        Err(err) => {
+            error!("{err}");
+            return;
+        }
+    };
+
+    let requests: Vec<_> = effects
+        .into_iter()
+        .map(|effect| {
+            GameplayEffectApplicationRequest::new(plan.target, effect, next_payload.clone())
+        })
+        .collect();
+
+    params.commands.queue(move |world: &mut World| {
+        if let Some(mut queue) = world.get_resource_mut::<GameplayEffectApplicationQueue>() {
+            for request in requests {
+                queue.push(request);
+            }
+        }
+    });
 }
 
 pub fn apply_gameplay_effect(
@@ -295,23 +850,49 @@ pub fn apply_gameplay_effect(
     effect_def: &Arc<GameplayEffect>,
     params: &mut AbilitySystemParams,
     payload: &EffectPayload,
-) -> bool {
-    let Some(plan) = prepare_gameplay_effect(target, effect_def, params, payload) else {
-        return false;
-    };
-
+) -> Result<(), EffectApplicationError> {
+    let plan = prepare_gameplay_effect(target, effect_def, params, payload)?;
     execute_gameplay_effect_plan(plan, params)
 }
 
 fn execute_instant_effect(
     plan: &GameplayEffectApplicationPlan,
     params: &mut AbilitySystemParams,
-) -> bool {
+) -> Result<(), EffectApplicationError> {
+    let active_modifiers = modifier_tag_requirements_mask(
+        &plan.spec,
+        payload_source_tags(&plan.payload, &params.tag_container_query),
+        params.tag_container_query.get(plan.target).ok(),
+    );
+    let execution_output = run_executions_for_params(plan, 1, params);
     let Ok(mut target_attrs_mut) = params.attr_set_query.get_mut(plan.target) else {
-        return false;
+        return Err(EffectApplicationError::MissingAttributeSet {
+            target: plan.target,
+        });
     };
-    apply_instant_modifiers(&mut target_attrs_mut, &plan.spec, 1);
-    true
+    apply_instant_modifiers(
+        &mut target_attrs_mut,
+        &plan.spec,
+        execution_output.as_ref(),
+        1,
+        &active_modifiers,
+        AttributeChangeCause::InstantEffect {
+            source: plan.source,
+        },
+    );
+    trigger_execution_event(
+        &mut params.commands,
+        execution_output,
+        plan.source,
+        plan.target,
+    );
+    params.commands.trigger(GameplayEffectLifecycleEvent::new(
+        plan.target,
+        None,
+        GameplayEffectLifecycle::Applied,
+        plan.payload.clone(),
+    ));
+    Ok(())
 }
 
 fn execute_stack_existing_effect(
@@ -319,23 +900,44 @@ fn execute_stack_existing_effect(
     handle: ActiveEffectHandle,
     new_stack_count: u32,
     params: &mut AbilitySystemParams,
-) -> bool {
+) -> Result<(), EffectApplicationError> {
     let Ok((_, mut active_effect, duration, period)) = params.active_effect_query.get_mut(handle)
     else {
         return execute_new_active_effect(plan, params);
     };
 
+    let lifecycle = if new_stack_count > active_effect.get_stack_count() {
+        GameplayEffectLifecycle::Stacked {
+            stack_count: new_stack_count,
+        }
+    } else {
+        GameplayEffectLifecycle::Refreshed
+    };
     active_effect.set_stack_count(new_stack_count);
+    let lifecycle_event = GameplayEffectLifecycleEvent::new(
+        active_effect.get_target(),
+        Some(handle),
+        lifecycle,
+        plan.payload.clone(),
+    );
     let existing_target = active_effect.get_target();
     let existing_spec = active_effect.get_spec().clone();
+    let active_modifiers = active_effect.get_active_modifiers().to_vec();
 
-    if matches!(
-        plan.spec.get_stacking_policy().get_duration_policy(),
-        StackDurationPolicy::RefreshOnSuccessfulStack
-    ) && let (EffectDurationTicksSpec::DurationTicks(duration_ticks), Some(mut duration)) =
+    if let (EffectDurationTicksSpec::DurationTicks(duration_ticks), Some(mut duration)) =
         (plan.spec.get_duration_spec(), duration)
     {
-        duration.remain_ticks = *duration_ticks;
+        match plan.spec.get_stacking_policy().get_duration_policy() {
+            StackDurationPolicy::KeepExisting => {}
+            StackDurationPolicy::RefreshOnSuccessfulStack => {
+                duration.refresh(*duration_ticks);
+            }
+            // A refresh at the stack limit replaces the stack closest to expiring.
+            StackDurationPolicy::IndependentPerStack => {
+                duration.add_stack(*duration_ticks);
+                duration.truncate_stacks(new_stack_count);
+            }
+        }
     }
 
     if matches!(
@@ -348,7 +950,9 @@ fn execute_stack_existing_effect(
 
     if !active_effect.is_inhibited() && existing_spec.get_period_spec().is_none() {
         let Ok(mut target_attrs_mut) = params.attr_set_query.get_mut(existing_target) else {
-            return false;
+            return Err(EffectApplicationError::MissingAttributeSet {
+                target: existing_target,
+            });
         };
         target_attrs_mut
             .remove_modifiers_for_attributes(handle, existing_spec.get_modified_attribute_ids());
@@ -357,27 +961,46 @@ fn execute_stack_existing_effect(
             &existing_spec,
             handle,
             new_stack_count,
+            &active_modifiers,
         );
     }
 
-    true
+    params.commands.trigger(lifecycle_event);
+    Ok(())
 }
 
 fn execute_new_active_effect(
     plan: &GameplayEffectApplicationPlan,
     params: &mut AbilitySystemParams,
-) -> bool {
-    let has_modifiers = !plan.spec.get_modifier_specs().is_empty();
+) -> Result<(), EffectApplicationError> {
+    let has_modifiers = has_any_modifiers(&plan.spec);
     let grants_tags = !plan.spec.get_def_tags().get_granted_tags().is_empty();
     if grants_tags && params.tag_container_query.get(plan.target).is_err() {
-        return false;
+        return Err(EffectApplicationError::MissingGameplayTagContainer {
+            target: plan.target,
+        });
     }
 
-    let mut entity_cmds = params.commands.spawn(ActiveGameplayEffect::new(
-        plan.spec.clone(),
-        plan.source,
-        plan.target,
-    ));
+    let active_modifiers = modifier_tag_requirements_mask(
+        &plan.spec,
+        payload_source_tags(&plan.payload, &params.tag_container_query),
+        params.tag_container_query.get(plan.target).ok(),
+    );
+    let mut active_effect =
+        ActiveGameplayEffect::new(plan.spec.clone(), plan.target, plan.payload.clone());
+    active_effect.set_active_modifiers(active_modifiers.clone());
+    let executes_on_application = plan
+        .spec
+        .get_period_spec()
+        .as_ref()
+        .is_some_and(|period| period.get_execute_on_applied() && period.get_period_ticks() > 0);
+    let execution_output = if executes_on_application {
+        run_executions_for_params(plan, 1, params)
+    } else {
+        None
+    };
+    // The component is inserted once nothing can fail, together with its granted abilities.
+    let mut entity_cmds = params.commands.spawn_empty();
 
     let effect_entity = entity_cmds.id();
     params
@@ -385,9 +1008,10 @@ fn execute_new_active_effect(
         .add(plan.target, effect_entity);
 
     if let EffectDurationTicksSpec::DurationTicks(duration) = plan.spec.get_duration_spec() {
-        entity_cmds.insert(ActiveEffectDurationTicks {
-            remain_ticks: *duration,
-        });
+        entity_cmds.insert(ActiveEffectDurationTicks::new(
+            *duration,
+            plan.spec.get_stacking_policy().get_duration_policy(),
+        ));
     }
 
     // Apply granted tags before modifiers so that tag failures don't leave
@@ -398,7 +1022,9 @@ fn execute_new_active_effect(
                 .active_effect_target_index
                 .remove(plan.target, effect_entity);
             params.commands.entity(effect_entity).despawn();
-            return false;
+            return Err(EffectApplicationError::MissingGameplayTagContainer {
+                target: plan.target,
+            });
         };
         target_tags.add_tags(
             plan.spec.get_def_tags().get_granted_tags(),
@@ -427,9 +1053,17 @@ fn execute_new_active_effect(
                         .active_effect_target_index
                         .remove(plan.target, effect_entity);
                     params.commands.entity(effect_entity).despawn();
-                    return false;
+                    return Err(EffectApplicationError::MissingAttributeSet {
+                        target: plan.target,
+                    });
                 };
-                apply_duration_modifiers(&mut target_attrs_mut, &plan.spec, effect_entity, 1);
+                apply_duration_modifiers(
+                    &mut target_attrs_mut,
+                    &plan.spec,
+                    effect_entity,
+                    1,
+                    &active_modifiers,
+                );
             }
         } else {
             if execute_on_application && has_modifiers {
@@ -447,9 +1081,18 @@ fn execute_new_active_effect(
                         .active_effect_target_index
                         .remove(plan.target, effect_entity);
                     params.commands.entity(effect_entity).despawn();
-                    return false;
+                    return Err(EffectApplicationError::MissingAttributeSet {
+                        target: plan.target,
+                    });
                 };
-                apply_instant_modifiers(&mut target_attrs_mut, &plan.spec, 1);
+                apply_instant_modifiers(
+                    &mut target_attrs_mut,
+                    &plan.spec,
+                    execution_output.as_ref(),
+                    1,
+                    &active_modifiers,
+                    AttributeChangeCause::ActiveEffect(effect_entity),
+                );
             }
             entity_cmds.insert(ActiveEffectPeriodTicks {
                 period_ticks,
@@ -471,14 +1114,42 @@ fn execute_new_active_effect(
                 .active_effect_target_index
                 .remove(plan.target, effect_entity);
             params.commands.entity(effect_entity).despawn();
-            return false;
+            return Err(EffectApplicationError::MissingAttributeSet {
+                target: plan.target,
+            });
         };
-        apply_duration_modifiers(&mut target_attrs_mut, &plan.spec, effect_entity, 1);
+        apply_duration_modifiers(
+            &mut target_attrs_mut,
+            &plan.spec,
+            effect_entity,
+            1,
+            &active_modifiers,
+        );
     }
 
+    if !plan.spec.get_def().get_granted_abilities().is_empty()
+        && let Ok(mut ability_system) = params.asc_query.get_mut(plan.target)
+    {
+        active_effect.granted_abilities =
+            give_granted_abilities(&mut ability_system, plan.spec.get_def());
+    }
+    entity_cmds.insert(active_effect);
     entity_cmds.set_parent_in_place(plan.target);
 
-    true
+    trigger_execution_event(
+        &mut params.commands,
+        execution_output,
+        plan.source,
+        plan.target,
+    );
+    params.commands.trigger(GameplayEffectLifecycleEvent::new(
+        plan.target,
+        Some(effect_entity),
+        GameplayEffectLifecycle::Applied,
+        plan.payload.clone(),
+    ));
+
+    Ok(())
 }
 
 pub fn remove_active_effect(handle: ActiveEffectHandle, params: &mut AbilitySystemParams) -> bool {
@@ -495,9 +1166,107 @@ pub fn remove_active_effect(handle: ActiveEffectHandle, params: &mut AbilitySyst
         &params.tag_manager,
         &mut params.active_effect_target_index,
     );
+    trigger_lifecycle_event(
+        &mut params.commands,
+        handle,
+        &effect,
+        GameplayEffectLifecycle::Removed,
+    );
+    true
+}
+
+/// Removes up to `stack_count` stacks of the active effect `handle`, removing the effect once no
+/// stack is left, and returns how many stacks were removed. Independent stack durations lose the
+/// stacks closest to expiring.
+pub fn remove_active_effect_stacks(
+    handle: ActiveEffectHandle,
+    stack_count: u32,
+    params: &mut AbilitySystemParams,
+) -> u32 {
+    if stack_count == 0 {
+        return 0;
+    }
+    let Ok((_, mut effect, duration, _)) = params.active_effect_query.get_mut(handle) else {
+        return 0;
+    };
+    let current_stack_count = effect.get_stack_count();
+    if stack_count >= current_stack_count {
+        remove_active_effect(handle, params);
+        return current_stack_count;
+    }
+
+    effect.set_stack_count(current_stack_count - stack_count);
+    if let Some(mut duration) = duration {
+        duration.truncate_stacks(effect.get_stack_count());
+    }
+    reapply_stacked_duration_modifiers(handle, &effect, &mut params.attr_set_query);
+    trigger_lifecycle_event(
+        &mut params.commands,
+        handle,
+        &effect,
+        GameplayEffectLifecycle::Unstacked {
+            stack_count: effect.get_stack_count(),
+        },
+    );
+    stack_count
+}
+
+/// Removes up to `stack_count` stacks of every active effect on `target` whose asset tags match
+/// any of `tags`, e.g. "remove one stack of every debuff", and returns the total removed.
+pub fn remove_active_effect_stacks_with_tags(
+    target: Entity,
+    tags: &[GameplayTag],
+    stack_count: u32,
+    params: &mut AbilitySystemParams,
+) -> u32 {
+    let handles = collect_active_effects_with_tags_for_params(
+        target,
+        tags,
+        &params.active_effect_target_index,
+        &mut params.active_effect_query,
+        &params.tag_manager,
+    );
+    handles
+        .into_iter()
+        .map(|handle| remove_active_effect_stacks(handle, stack_count, params))
+        .sum()
+}
+
+/// Adjusts the remaining duration of the active effect `handle`, and of each of its independent
+/// stacks. Returns `false` when the effect does not exist or has no duration.
+pub fn adjust_active_effect_duration(
+    handle: ActiveEffectHandle,
+    adjustment: EffectDurationAdjustment,
+    params: &mut AbilitySystemParams,
+) -> bool {
+    let Ok((_, _, Some(mut duration), _)) = params.active_effect_query.get_mut(handle) else {
+        return false;
+    };
+    duration.adjust(adjustment);
     true
 }
 
+/// Adjusts the remaining duration of every active effect on `target` whose asset tags match any
+/// of `tags`, e.g. "reduce stun duration by 50%", and returns how many effects were adjusted.
+pub fn adjust_active_effect_durations_with_tags(
+    target: Entity,
+    tags: &[GameplayTag],
+    adjustment: EffectDurationAdjustment,
+    params: &mut AbilitySystemParams,
+) -> usize {
+    let handles = collect_active_effects_with_tags_for_params(
+        target,
+        tags,
+        &params.active_effect_target_index,
+        &mut params.active_effect_query,
+        &params.tag_manager,
+    );
+    handles
+        .into_iter()
+        .filter(|&handle| adjust_active_effect_duration(handle, adjustment, params))
+        .count()
+}
+
 pub fn remove_active_effects_with_tags(
     target: Entity,
     tags: &[GameplayTag],
@@ -523,6 +1292,189 @@ pub fn remove_active_effects_with_tags(
     removed_count
 }
 
+/// Re-makes the spec of every active effect created from `old_def` with `new_def`, e.g. after
+/// the definition was hot reloaded, and returns their handles.
+///
+/// Magnitudes are recalculated from each effect's original payload. Stack counts, remaining
+/// duration and period progress are kept, clamped to the new definition; an effect whose new
+/// definition is instant is removed.
+pub fn rebuild_active_effects_from_definition(
+    old_def: &Arc<GameplayEffect>,
+    new_def: &Arc<GameplayEffect>,
+    params: &mut AbilitySystemParams,
+) -> Vec<ActiveEffectHandle> {
+    let handles: Vec<ActiveEffectHandle> = params
+        .active_effect_query
+        .iter()
+        .filter(|(_, effect, _, _)| Arc::ptr_eq(effect.get_spec().get_def(), old_def))
+        .map(|(handle, _, _, _)| handle)
+        .collect();
+    for &handle in &handles {
+        rebuild_active_effect(handle, new_def, params);
+    }
+    handles
+}
+
+fn rebuild_active_effect(
+    handle: ActiveEffectHandle,
+    new_def: &Arc<GameplayEffect>,
+    params: &mut AbilitySystemParams,
+) {
+    let Ok((_, effect, _, _)) = params.active_effect_query.get(handle) else {
+        return;
+    };
+    let target = effect.get_target();
+    let payload = effect.get_payload().clone();
+
+    let spec = {
+        let context = crate::gameplay_effects::EffectContext {
+            target: Some(target),
+            payload: &payload,
+            fixed_timestep: params.fixed_time.timestep(),
+            attr_set_query: &params.attr_set_query.as_readonly(),
+            tag_container_query: &params.tag_container_query.as_readonly(),
+            asc_query: &params.asc_query.as_readonly(),
+        };
+
+        new_def.make_spec(&context)
+    };
+    if spec.get_duration_spec().is_instant() {
+        remove_active_effect(handle, params);
+        return;
+    }
+    if !new_def.get_executions().is_empty() && !executes_instantly(&spec) {
+        warn!(
+            "Keeping active effect {handle:?}: the new definition has executions but is not periodic"
+        );
+        return;
+    }
+
+    let active_modifiers = modifier_tag_requirements_mask(
+        &spec,
+        payload_source_tags(&payload, &params.tag_container_query),
+        params.tag_container_query.get(target).ok(),
+    );
+    let Ok((_, mut effect, duration, period)) = params.active_effect_query.get_mut(handle) else {
+        return;
+    };
+    let old_spec = std::mem::replace(&mut effect.spec, spec);
+    effect.set_active_modifiers(active_modifiers);
+    let stack_limit = effect.spec.get_stacking_policy().get_stack_limit();
+    if stack_limit != 0 && effect.get_stack_count() > stack_limit {
+        effect.set_stack_count(stack_limit);
+    }
+
+    let duration_policy = effect.spec.get_stacking_policy().get_duration_policy();
+    match (effect.spec.get_duration_spec(), duration) {
+        (EffectDurationTicksSpec::DurationTicks(ticks), Some(mut duration)) => {
+            duration.fit_to(*ticks, effect.get_stack_count(), duration_policy);
+        }
+        (EffectDurationTicksSpec::DurationTicks(ticks), None) => {
+            let mut duration = ActiveEffectDurationTicks::new(*ticks, duration_policy);
+            duration.fit_to(*ticks, effect.get_stack_count(), duration_policy);
+            params.commands.entity(handle).insert(duration);
+        }
+        (_, Some(_)) => {
+            params
+                .commands
+                .entity(handle)
+                .remove::<ActiveEffectDurationTicks>();
+        }
+        (_, None) => {}
+    }
+
+    let period_ticks = effect
+        .spec
+        .get_period_spec()
+        .as_ref()
+        .map(|period| period.get_period_ticks())
+        .filter(|&period_ticks| period_ticks > 0);
+    match (period_ticks, period) {
+        (Some(period_ticks), Some(mut period)) => {
+            period.period_ticks = period_ticks;
+            period.current_tick = period.current_tick.min(period_ticks - 1);
+        }
+        (Some(period_ticks), None) => {
+            params
+                .commands
+                .entity(handle)
+                .insert(ActiveEffectPeriodTicks {
+                    period_ticks,
+                    current_tick: 0,
+                });
+        }
+        (None, Some(_)) => {
+            params
+                .commands
+                .entity(handle)
+                .remove::<ActiveEffectPeriodTicks>();
+        }
+        (None, None) => {}
+    }
+
+    if !effect.is_inhibited() {
+        if let Ok(mut attr_set) = params.attr_set_query.get_mut(target) {
+            attr_set.remove_modifiers_for_attributes(handle, old_spec.get_modified_attribute_ids());
+            if !executes_instantly(&effect.spec) {
+                apply_duration_modifiers(
+                    &mut attr_set,
+                    &effect.spec,
+                    handle,
+                    effect.get_stack_count(),
+                    effect.get_active_modifiers(),
+                );
+            }
+        }
+        if let Ok(mut tag_container) = params.tag_container_query.get_mut(target) {
+            tag_container.remove_tags(
+                old_spec.get_def_tags().get_granted_tags(),
+                &params.tag_manager,
+            );
+            tag_container.add_tags(
+                effect.spec.get_def_tags().get_granted_tags(),
+                &params.tag_manager,
+            );
+        }
+    }
+
+    // Abilities granted by the old definition go with the same removal policy as on removal.
+    let old_granted_abilities = std::mem::take(&mut effect.granted_abilities);
+    if !old_granted_abilities.is_empty() {
+        params.commands.queue(move |world: &mut World| {
+            remove_granted_abilities(world, target, &old_granted_abilities);
+        });
+    }
+    if !new_def.get_granted_abilities().is_empty()
+        && let Ok(mut ability_system) = params.asc_query.get_mut(target)
+    {
+        effect.granted_abilities = give_granted_abilities(&mut ability_system, new_def);
+    }
+
+    trigger_lifecycle_event(
+        &mut params.commands,
+        handle,
+        &effect,
+        GameplayEffectLifecycle::Rebuilt,
+    );
+}
+
+fn give_granted_abilities(
+    ability_system: &mut AbilitySystemComponent,
+    def: &GameplayEffect,
+) -> Vec<(AbilitySpecHandle, GrantedAbilityRemovalPolicy)> {
+    def.get_granted_abilities()
+        .iter()
+        .map(|granted| {
+            let handle = ability_system.give_ability(
+                granted.get_ability().clone(),
+                granted.get_level(),
+                granted.get_input_id(),
+            );
+            (handle, granted.get_removal_policy())
+        })
+        .collect()
+}
+
 pub fn get_active_effects_on_target(
     target: Entity,
     target_index: &ActiveGameplayEffectTargetIndex,
@@ -553,6 +1505,26 @@ pub fn has_active_effect_with_tags(
         })
 }
 
+/// Builds the breakdown of `id` on `target` and resolves each source effect's asset tags.
+pub fn get_attribute_breakdown(
+    target: Entity,
+    id: AttributeId,
+    attr_query: &mut Query<&mut AttributeSet>,
+    active_effect_query: &Query<(Entity, &ActiveGameplayEffect)>,
+) -> Option<AttributeBreakdown> {
+    let mut breakdown = attr_query
+        .get_mut(target)
+        .ok()?
+        .get_attribute_breakdown(id)?;
+    breakdown.resolve_source_asset_tags(|handle| {
+        active_effect_query
+            .get(handle)
+            .ok()
+            .map(|(_, effect)| effect.get_spec().get_def_tags().get_asset_tags().to_vec())
+    });
+    Some(breakdown)
+}
+
 pub fn cleanup_active_gameplay_effect(
     commands: &mut Commands,
     handle: ActiveEffectHandle,
@@ -576,6 +1548,14 @@ pub fn cleanup_active_gameplay_effect(
         );
     }
 
+    if !effect.granted_abilities.is_empty() {
+        let target = effect.get_target();
+        let granted_abilities = effect.granted_abilities.clone();
+        commands.queue(move |world: &mut World| {
+            remove_granted_abilities(world, target, &granted_abilities);
+        });
+    }
+
     commands.entity(handle).despawn();
     target_index.remove(effect.get_target(), handle);
 }
@@ -591,47 +1571,48 @@ pub fn tick_effect_duration_system(
     mut tag_query: Query<&mut GameplayTagContainer>,
     tag_manager: Res<GameplayTagManager>,
     mut target_index: ResMut<ActiveGameplayEffectTargetIndex>,
+    time_query: Query<&GameplayTimeScale>,
 ) {
     for (entity, mut duration, mut effect) in query.iter_mut() {
-        if duration.remain_ticks > 0 {
-            duration.remain_ticks -= 1;
-        }
-
-        if duration.remain_ticks == 0 {
-            if matches!(
-                effect
-                    .get_spec()
-                    .get_stacking_policy()
-                    .get_expiration_policy(),
-                StackExpirationPolicy::RemoveSingleStack
-            ) && effect.get_stack_count() > 1
-            {
+        let stacking_policy = effect.get_spec().get_stacking_policy();
+        let independent_stacks = matches!(
+            stacking_policy.get_duration_policy(),
+            StackDurationPolicy::IndependentPerStack
+        );
+        let remove_single_stack = matches!(
+            stacking_policy.get_expiration_policy(),
+            StackExpirationPolicy::RemoveSingleStack
+        );
+        let mut stacks_expired = false;
+        let mut expired = false;
+        for _ in 0..get_gameplay_time_ticks(&time_query, effect.get_target()) {
+            let expired_stacks = duration.tick();
+            if duration.remain_ticks != 0 {
+                if expired_stacks > 0 {
+                    let new_stack_count = effect.get_stack_count().saturating_sub(expired_stacks);
+                    effect.set_stack_count(new_stack_count.max(1));
+                    stacks_expired = true;
+                }
+                continue;
+            }
+
+            if !independent_stacks && remove_single_stack && effect.get_stack_count() > 1 {
                 let new_stack_count = effect.get_stack_count() - 1;
                 effect.set_stack_count(new_stack_count);
                 if let EffectDurationTicksSpec::DurationTicks(duration_ticks) =
                     effect.get_spec().get_duration_spec()
                 {
-                    duration.remain_ticks = *duration_ticks;
-                }
-
-                if !effect.is_inhibited()
-                    && effect.get_spec().get_period_spec().is_none()
-                    && let Ok(mut attr_set) = attr_query.get_mut(effect.get_target())
-                {
-                    attr_set.remove_modifiers_for_attributes(
-                        entity,
-                        effect.get_spec().get_modified_attribute_ids(),
-                    );
-                    apply_duration_modifiers(
-                        &mut attr_set,
-                        effect.get_spec(),
-                        entity,
-                        effect.get_stack_count(),
-                    );
+                    duration.refresh(*duration_ticks);
                 }
+                stacks_expired = true;
                 continue;
             }
 
+            expired = true;
+            break;
+        }
+
+        if expired {
             cleanup_active_gameplay_effect(
                 &mut commands,
                 entity,
@@ -641,6 +1622,24 @@ pub fn tick_effect_duration_system(
                 &tag_manager,
                 &mut target_index,
             );
+            trigger_lifecycle_event(
+                &mut commands,
+                entity,
+                &effect,
+                GameplayEffectLifecycle::Expired,
+            );
+        } else if stacks_expired {
+            // Stacks that expire within the same frame rebuild the aggregator once and report
+            // one `Unstacked` with the remaining count.
+            reapply_stacked_duration_modifiers(entity, &effect, &mut attr_query);
+            trigger_lifecycle_event(
+                &mut commands,
+                entity,
+                &effect,
+                GameplayEffectLifecycle::Unstacked {
+                    stack_count: effect.get_stack_count(),
+                },
+            );
         }
     }
 }
@@ -664,9 +1663,22 @@ pub fn update_active_effect_tag_requirements_system(
                 &tag_manager,
                 &mut target_index,
             );
+            trigger_lifecycle_event(
+                &mut commands,
+                handle,
+                &effect,
+                GameplayEffectLifecycle::Removed,
+            );
             continue;
         }
 
+        update_active_effect_modifier_requirements(
+            handle,
+            &mut effect,
+            &mut attr_query,
+            &tag_query,
+        );
+
         let ongoing_passes = passes_ongoing_requirements(&effect, &tag_query);
         match (ongoing_passes, effect.is_inhibited()) {
             (false, false) => {
@@ -677,6 +1689,12 @@ pub fn update_active_effect_tag_requirements_system(
                     &mut tag_query,
                     &tag_manager,
                 );
+                trigger_lifecycle_event(
+                    &mut commands,
+                    handle,
+                    &effect,
+                    GameplayEffectLifecycle::Inhibited,
+                );
             }
             (true, true) => {
                 uninhibit_active_effect(
@@ -686,26 +1704,78 @@ pub fn update_active_effect_tag_requirements_system(
                     &mut tag_query,
                     &tag_manager,
                 );
+                trigger_lifecycle_event(
+                    &mut commands,
+                    handle,
+                    &effect,
+                    GameplayEffectLifecycle::Uninhibited,
+                );
             }
             _ => {}
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn tick_effect_period_system(
-    mut query: Query<(&mut ActiveEffectPeriodTicks, &ActiveGameplayEffect)>,
-    mut attr_query: Query<&mut AttributeSet>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ActiveEffectPeriodTicks, &ActiveGameplayEffect)>,
+    mut attr_query: Query<&'static mut AttributeSet>,
+    tag_query: Query<&'static GameplayTagContainer>,
+    asc_query: Query<&'static AbilitySystemComponent>,
+    time_query: Query<&GameplayTimeScale>,
+    fixed_time: Res<Time<Fixed>>,
+    mut random_gen: ResMut<Random>,
 ) {
-    for (mut period, effect) in query.iter_mut() {
+    for (handle, mut period, effect) in query.iter_mut() {
         if effect.is_inhibited() {
             continue;
         }
 
-        period.current_tick += 1;
-        if period.current_tick >= period.period_ticks {
+        for _ in 0..get_gameplay_time_ticks(&time_query, effect.get_target()) {
+            period.current_tick += 1;
+            if period.current_tick < period.period_ticks {
+                continue;
+            }
+
             period.current_tick = 0;
+            let execution_output = {
+                let context = EffectContext {
+                    target: Some(effect.get_target()),
+                    payload: effect.get_payload(),
+                    fixed_timestep: fixed_time.timestep(),
+                    attr_set_query: &attr_query.as_readonly(),
+                    tag_container_query: &tag_query,
+                    asc_query: &asc_query,
+                };
+                run_executions(
+                    &context,
+                    effect.get_spec(),
+                    effect.get_stack_count(),
+                    &mut random_gen,
+                )
+            };
             if let Ok(mut attr_set) = attr_query.get_mut(effect.get_target()) {
-                apply_instant_modifiers(&mut attr_set, effect.get_spec(), effect.get_stack_count());
+                apply_instant_modifiers(
+                    &mut attr_set,
+                    effect.get_spec(),
+                    execution_output.as_ref(),
+                    effect.get_stack_count(),
+                    effect.get_active_modifiers(),
+                    AttributeChangeCause::ActiveEffect(handle),
+                );
+                trigger_execution_event(
+                    &mut commands,
+                    execution_output,
+                    effect.get_source(),
+                    effect.get_target(),
+                );
+                trigger_lifecycle_event(
+                    &mut commands,
+                    handle,
+                    effect,
+                    GameplayEffectLifecycle::Executed,
+                );
             }
         }
     }
@@ -751,30 +1821,41 @@ fn find_stackable_active_effect(
         })
 }
 
-fn passes_application_requirements(
-    source: Entity,
+fn check_application_requirements(
+    payload: &EffectPayload,
     target: Entity,
     incoming_tags: &crate::gameplay_effects::EffectTags,
     params: &AbilitySystemParams,
-) -> bool {
-    let source_tags = params.tag_container_query.get(source).ok();
+) -> Result<(), EffectApplicationError> {
+    let source_tags = payload_source_tags(payload, &params.tag_container_query);
     let target_tags = params.tag_container_query.get(target).ok();
 
-    incoming_tags
+    if !incoming_tags
         .get_source_application_tags()
         .passes(source_tags)
-        && incoming_tags
-            .get_target_application_tags()
-            .passes(target_tags)
+    {
+        Err(EffectApplicationError::BlockedBySourceTags {
+            source: payload.get_source(),
+        })
+    } else if !incoming_tags
+        .get_target_application_tags()
+        .passes(target_tags)
+    {
+        Err(EffectApplicationError::BlockedByTargetTags { target })
+    } else {
+        Ok(())
+    }
 }
 
-fn is_blocked_by_application_immunity(
-    source: Entity,
+/// Returns the first uninhibited active effect on `target` whose immunity query matches, and the
+/// index of that query.
+fn find_blocking_application_immunity(
+    payload: &EffectPayload,
     target: Entity,
     incoming_tags: &crate::gameplay_effects::EffectTags,
     params: &mut AbilitySystemParams,
-) -> bool {
-    let source_tags = params.tag_container_query.get(source).ok();
+) -> Option<(ActiveEffectHandle, usize)> {
+    let source_tags = payload_source_tags(payload, &params.tag_container_query);
     let incoming_asset_bits =
         tag_bits_from_tags_with_manager(incoming_tags.get_asset_tags(), &params.tag_manager);
 
@@ -783,21 +1864,20 @@ fn is_blocked_by_application_immunity(
         .handles_for(target)
         .to_vec()
         .into_iter()
-        .any(|handle| {
-            let Ok((_, active_effect, _, _)) = params.active_effect_query.get_mut(handle) else {
-                return false;
-            };
+        .find_map(|handle| {
+            let (_, active_effect, _, _) = params.active_effect_query.get_mut(handle).ok()?;
             if active_effect.is_inhibited() {
-                return false;
+                return None;
             }
             active_effect
                 .get_spec()
                 .get_def_tags()
                 .get_granted_application_immunity()
                 .iter()
-                .any(|immunity| {
+                .position(|immunity| {
                     immunity.matches_tag_bits(source_tags, incoming_asset_bits.as_ref())
                 })
+                .map(|query_index| (handle, query_index))
         })
 }
 
@@ -871,6 +1951,7 @@ fn uninhibit_active_effect(
             effect.get_spec(),
             handle,
             effect.get_stack_count(),
+            effect.get_active_modifiers(),
         );
     }
 
@@ -941,21 +2022,49 @@ fn remove_collected_active_effects_for_params(
             tag_manager,
             target_index,
         );
+        trigger_lifecycle_event(commands, handle, &effect, GameplayEffectLifecycle::Removed);
     }
 }
 
+/// Rebuilds the aggregator entries of `effect` after its stack count changed.
+fn reapply_stacked_duration_modifiers(
+    handle: ActiveEffectHandle,
+    effect: &ActiveGameplayEffect,
+    attr_query: &mut Query<&mut AttributeSet>,
+) {
+    if effect.is_inhibited() || effect.get_spec().get_period_spec().is_some() {
+        return;
+    }
+    let Ok(mut attr_set) = attr_query.get_mut(effect.get_target()) else {
+        return;
+    };
+    attr_set
+        .remove_modifiers_for_attributes(handle, effect.get_spec().get_modified_attribute_ids());
+    apply_duration_modifiers(
+        &mut attr_set,
+        effect.get_spec(),
+        handle,
+        effect.get_stack_count(),
+        effect.get_active_modifiers(),
+    );
+}
+
 fn apply_duration_modifiers(
     attr_set: &mut AttributeSet,
     spec: &GameplayEffectSpec,
     handle: ActiveEffectHandle,
     stack_count: u32,
+    active_modifiers: &[bool],
 ) {
-    let stack_multiplier = stack_multiplier(
-        spec.get_stacking_policy().get_magnitude_policy(),
-        stack_count,
-    );
-    for mod_spec in spec.get_modifier_specs() {
-        let stacked_spec = mod_spec.scaled_by_stack(stack_multiplier);
+    let stack_multiplier = spec
+        .get_stacking_policy()
+        .get_magnitude_policy()
+        .get_multiplier(stack_count);
+    for (index, mod_spec) in spec.get_modifier_specs().iter().enumerate() {
+        if !is_modifier_active(active_modifiers, index) {
+            continue;
+        }
+        let stacked_spec = mod_spec.scaled_by(stack_multiplier);
         attr_set.apply_duration_modifier(&stacked_spec, handle);
     }
 }
@@ -963,22 +2072,178 @@ fn apply_duration_modifiers(
 fn apply_instant_modifiers(
     attr_set: &mut AttributeSet,
     spec: &GameplayEffectSpec,
+    execution_output: Option<&GameplayEffectExecutionOutput>,
     stack_count: u32,
+    active_modifiers: &[bool],
+    cause: AttributeChangeCause,
 ) {
-    let stack_multiplier = stack_multiplier(
-        spec.get_stacking_policy().get_magnitude_policy(),
-        stack_count,
-    );
-    for mod_spec in spec.get_modifier_specs() {
-        let stacked_spec = mod_spec.scaled_by_stack(stack_multiplier);
+    let stack_multiplier = spec
+        .get_stacking_policy()
+        .get_magnitude_policy()
+        .get_multiplier(stack_count);
+    attr_set.set_change_cause(Some(cause));
+    for (index, mod_spec) in spec.get_modifier_specs().iter().enumerate() {
+        if !is_modifier_active(active_modifiers, index) {
+            continue;
+        }
+        let stacked_spec = mod_spec.scaled_by(stack_multiplier);
         attr_set.apply_instant_modifier(&stacked_spec);
     }
+    for mod_spec in execution_output
+        .iter()
+        .flat_map(|output| output.get_modifiers())
+    {
+        attr_set.apply_instant_modifier(&mod_spec.scaled_by(stack_multiplier));
+    }
+    attr_set.set_change_cause(None);
+}
+
+/// Instant effects and periodic effects apply their modifiers, and execution outputs, instantly.
+fn executes_instantly(spec: &GameplayEffectSpec) -> bool {
+    spec.get_duration_spec().is_instant()
+        || spec
+            .get_period_spec()
+            .as_ref()
+            .is_some_and(|period| period.get_period_ticks() > 0)
+}
+
+/// Executions count as modifiers: their outputs are only known once the effect executes.
+fn has_any_modifiers(spec: &GameplayEffectSpec) -> bool {
+    !spec.get_modifier_specs().is_empty() || !spec.get_def().get_executions().is_empty()
+}
+
+/// Runs the effect's executions for one execution of `spec`; `None` when it has none.
+fn run_executions(
+    context: &EffectContext,
+    spec: &GameplayEffectSpec,
+    stack_count: u32,
+    random: &mut Random,
+) -> Option<GameplayEffectExecutionOutput> {
+    let def = spec.get_def();
+    if def.get_executions().is_empty() {
+        return None;
+    }
+    Some(def.execute(context, spec, stack_count, random))
+}
+
+fn run_executions_for_params(
+    plan: &GameplayEffectApplicationPlan,
+    stack_count: u32,
+    params: &mut AbilitySystemParams,
+) -> Option<GameplayEffectExecutionOutput> {
+    let context = EffectContext {
+        target: Some(plan.target),
+        payload: &plan.payload,
+        fixed_timestep: params.fixed_time.timestep(),
+        attr_set_query: &params.attr_set_query.as_readonly(),
+        tag_container_query: &params.tag_container_query.as_readonly(),
+        asc_query: &params.asc_query.as_readonly(),
+    };
+    run_executions(&context, &plan.spec, stack_count, &mut params.random_gen)
 }
 
-fn stack_multiplier(policy: StackMagnitudePolicy, stack_count: u32) -> u32 {
-    match policy {
-        StackMagnitudePolicy::None => 1,
-        StackMagnitudePolicy::Linear => stack_count,
+fn trigger_execution_event(
+    commands: &mut Commands,
+    execution_output: Option<GameplayEffectExecutionOutput>,
+    source: Entity,
+    target: Entity,
+) {
+    if let Some(output) = execution_output {
+        commands.trigger(GameplayEffectExecutedEvent::new(target, source, output));
+    }
+}
+
+fn is_modifier_active(active_modifiers: &[bool], index: usize) -> bool {
+    active_modifiers.get(index).copied().unwrap_or(true)
+}
+
+/// Source tags for application requirements, application immunity and modifier requirements:
+/// tags captured with the source snapshot take precedence over the live source tags.
+fn payload_source_tags<'a>(
+    payload: &'a EffectPayload,
+    tag_query: &'a Query<&mut GameplayTagContainer>,
+) -> Option<&'a GameplayTagContainer> {
+    payload
+        .get_source_snapshot()
+        .and_then(AttributeSetSnapshot::get_tags)
+        .or_else(|| tag_query.get(payload.get_source()).ok())
+}
+
+fn modifier_tag_requirements_mask(
+    spec: &GameplayEffectSpec,
+    source_tags: Option<&GameplayTagContainer>,
+    target_tags: Option<&GameplayTagContainer>,
+) -> Vec<bool> {
+    let modifiers = spec.get_def().get_modifiers();
+    (0..spec.get_modifier_specs().len())
+        .map(|index| {
+            modifiers
+                .get(index)
+                .is_none_or(|modifier| modifier.passes_tag_requirements(source_tags, target_tags))
+        })
+        .collect()
+}
+
+/// Re-evaluates per-modifier tag requirements and swaps only the modifiers whose state flipped,
+/// by rebuilding this effect's aggregator entries on the attributes they touch.
+fn update_active_effect_modifier_requirements(
+    handle: ActiveEffectHandle,
+    effect: &mut ActiveGameplayEffect,
+    attr_query: &mut Query<&mut AttributeSet>,
+    tag_query: &Query<&mut GameplayTagContainer>,
+) {
+    if !effect
+        .get_spec()
+        .get_def()
+        .get_modifiers()
+        .iter()
+        .any(|modifier| modifier.has_tag_requirements())
+    {
+        return;
+    }
+
+    let active_modifiers = modifier_tag_requirements_mask(
+        effect.get_spec(),
+        payload_source_tags(effect.get_payload(), tag_query),
+        tag_query.get(effect.get_target()).ok(),
+    );
+    if active_modifiers == effect.get_active_modifiers() {
+        return;
+    }
+
+    let changed_ids: Vec<AttributeId> = effect
+        .get_spec()
+        .get_modifier_specs()
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| {
+            is_modifier_active(effect.get_active_modifiers(), index)
+                != is_modifier_active(&active_modifiers, index)
+        })
+        .map(|(_, mod_spec)| mod_spec.get_id())
+        .collect();
+    effect.set_active_modifiers(active_modifiers);
+
+    if effect.is_inhibited() || effect.get_spec().get_period_spec().is_some() {
+        return;
+    }
+    let Ok(mut attr_set) = attr_query.get_mut(effect.get_target()) else {
+        return;
+    };
+
+    attr_set.remove_modifiers_for_attributes(handle, changed_ids.iter().copied());
+    let stack_multiplier = effect
+        .get_spec()
+        .get_stacking_policy()
+        .get_magnitude_policy()
+        .get_multiplier(effect.get_stack_count());
+    for (index, mod_spec) in effect.get_spec().get_modifier_specs().iter().enumerate() {
+        if is_modifier_active(effect.get_active_modifiers(), index)
+            && changed_ids.contains(&mod_spec.get_id())
+        {
+            let stacked_spec = mod_spec.scaled_by(stack_multiplier);
+            attr_set.apply_duration_modifier(&stacked_spec, handle);
+        }
     }
 }
 
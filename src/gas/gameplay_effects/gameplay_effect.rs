@@ -1,21 +1,40 @@
+use super::gameplay_effect_execution::{
+    GameplayEffectExecutionCalculation, GameplayEffectExecutionOutput,
+    GameplayEffectExecutionParams,
+};
 use super::gameplay_effect_spec::{
     EffectDurationTicksSpec, EffectPeriodTicksSpec, GameplayEffectSpec,
 };
+use crate::Random;
 use crate::ability_system::AbilitySystemComponent;
+use crate::attributes::AttributeId;
 use crate::attributes::{AttributeSet, AttributeSetSnapshot};
+use crate::curves::RealCurve;
+use crate::gameplay_abilities::GameplayAbility;
 use crate::gameplay_tags::{
     GameplayTag, GameplayTagBits, GameplayTagContainer, GameplayTagManager, tag_bits_from_tags,
     tag_bits_from_tags_with_manager,
 };
-use crate::modifiers::{Modifier, ModifierMagnitude, ModifierOperation};
+use crate::modifiers::{
+    AttributeCaptureSide, AttributeCaptureValue, Modifier, ModifierMagnitude, ModifierOperation,
+    SetByCallerKey,
+};
+use crate::settings::GameplayAbilitySystemSettings;
+use crate::unique_names::UniqueName;
 use bevy::ecs::entity::Entity;
 use bevy::ecs::system::Query;
 use bevy::prelude::Res;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub struct EffectContext<'w, 's> {
     pub target: Option<Entity>,
     pub payload: &'w EffectPayload,
+    /// `Time<Fixed>` timestep, used to convert durations and periods authored in seconds.
+    pub fixed_timestep: Duration,
     pub attr_set_query: &'w Query<'w, 's, &'static AttributeSet>,
     pub tag_container_query: &'w Query<'w, 's, &'static GameplayTagContainer>,
     pub asc_query: &'w Query<'w, 's, &'static AbilitySystemComponent>,
@@ -37,31 +56,153 @@ impl<'w, 's> EffectContext<'w, 's> {
     pub fn source_snapshot(&self) -> Option<&AttributeSetSnapshot> {
         self.payload.get_source_snapshot()
     }
+
+    /// Source tags captured in the payload snapshot, otherwise the live source tags.
+    pub fn source_tags(&self) -> Option<&GameplayTagContainer> {
+        self.source_snapshot()
+            .and_then(AttributeSetSnapshot::get_tags)
+            .or_else(|| self.tag_container_query.get(self.source()).ok())
+    }
+
+    pub fn source_attributes(&self) -> Option<&AttributeSet> {
+        self.attr_set_query.get(self.source()).ok()
+    }
+
+    pub fn target_attributes(&self) -> Option<&AttributeSet> {
+        self.target
+            .and_then(|target| self.attr_set_query.get(target).ok())
+    }
+
+    /// Reads `attribute` of `side`; with `snapshot`, source values come from the payload snapshot
+    /// when the payload carries one.
+    pub fn capture_attribute(
+        &self,
+        attribute: AttributeId,
+        side: AttributeCaptureSide,
+        snapshot: bool,
+        value: AttributeCaptureValue,
+    ) -> Option<f64> {
+        let (base, current) = if snapshot
+            && side == AttributeCaptureSide::Source
+            && let Some(snapshot) = self.source_snapshot()
+        {
+            (
+                snapshot.get_base_value(attribute),
+                snapshot.get_current_value(attribute),
+            )
+        } else {
+            let attributes = match side {
+                AttributeCaptureSide::Source => self.source_attributes(),
+                AttributeCaptureSide::Target => self.target_attributes(),
+            }?;
+            (
+                attributes.get_base_value(attribute),
+                attributes.calculate_current_value(attribute),
+            )
+        };
+        match value {
+            AttributeCaptureValue::Base => base,
+            AttributeCaptureValue::Current => current,
+            AttributeCaptureValue::Bonus => Some(current? - base?),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameplayEffectChainError {
+    DepthExceeded { max_depth: u8 },
+}
+
+impl fmt::Display for GameplayEffectChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameplayEffectChainError::DepthExceeded { max_depth } => {
+                write!(f, "gameplay effect chain exceeded max depth {max_depth}")
+            }
+        }
+    }
 }
 
+impl Error for GameplayEffectChainError {}
+
 #[derive(Clone)]
 pub struct EffectPayload {
     source: Entity,
     causer: Option<Entity>,
     level: u32,
-    source_snapshot: Option<AttributeSetSnapshot>,
+    /// Shared so that payload clones kept by active effects and events stay cheap.
+    source_snapshot: Option<Arc<AttributeSetSnapshot>>,
+    set_by_caller_magnitudes: Vec<(SetByCallerKey, f64)>,
+    effect_chain_depth: u8,
 }
 
 impl EffectPayload {
+    pub const MAX_EFFECT_CHAIN_DEPTH: u8 =
+        GameplayAbilitySystemSettings::GAMEPLAY_EFFECT_CHAIN_MAX_DEPTH;
+
     pub fn new(source: Entity, causer: Option<Entity>, level: u32) -> Self {
         Self {
             source,
             causer,
             level,
             source_snapshot: None,
+            set_by_caller_magnitudes: Vec::new(),
+            effect_chain_depth: 0,
+        }
+    }
+
+    /// Same payload one step deeper, used for conditional effects queued by a successful application.
+    pub fn next_in_effect_chain(&self) -> Result<Self, GameplayEffectChainError> {
+        if self.effect_chain_depth >= Self::MAX_EFFECT_CHAIN_DEPTH {
+            return Err(GameplayEffectChainError::DepthExceeded {
+                max_depth: Self::MAX_EFFECT_CHAIN_DEPTH,
+            });
         }
+
+        let mut payload = self.clone();
+        payload.effect_chain_depth += 1;
+        Ok(payload)
     }
 
     pub fn with_source_snapshot(mut self, source_snapshot: AttributeSetSnapshot) -> Self {
-        self.source_snapshot = Some(source_snapshot);
+        self.source_snapshot = Some(Arc::new(source_snapshot));
+        self
+    }
+
+    /// Value read by `ModifierMagnitude::SetByCaller(key)`; replaces an earlier value for `key`.
+    pub fn with_set_by_caller_magnitude(
+        mut self,
+        key: impl Into<SetByCallerKey>,
+        value: f64,
+    ) -> Self {
+        self.set_set_by_caller_magnitude(key, value);
         self
     }
 
+    pub fn set_set_by_caller_magnitude(&mut self, key: impl Into<SetByCallerKey>, value: f64) {
+        let key = key.into();
+        match self
+            .set_by_caller_magnitudes
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            Some((_, existing_value)) => *existing_value = value,
+            None => self.set_by_caller_magnitudes.push((key, value)),
+        }
+    }
+
+    pub fn get_set_by_caller_magnitude(&self, key: impl Into<SetByCallerKey>) -> Option<f64> {
+        let key = key.into();
+        self.set_by_caller_magnitudes
+            .iter()
+            .find_map(|(existing, value)| (*existing == key).then_some(*value))
+    }
+
+    /// Set-by-caller values in the order they were first set.
+    pub fn get_set_by_caller_magnitudes(&self) -> &[(SetByCallerKey, f64)] {
+        &self.set_by_caller_magnitudes
+    }
+
     pub fn get_source(&self) -> Entity {
         self.source
     }
@@ -75,92 +216,217 @@ impl EffectPayload {
     }
 
     pub fn get_source_snapshot(&self) -> Option<&AttributeSetSnapshot> {
-        self.source_snapshot.as_ref()
+        self.source_snapshot.as_deref()
+    }
+
+    /// Number of conditional effects between this payload and the directly applied effect.
+    pub fn get_effect_chain_depth(&self) -> u8 {
+        self.effect_chain_depth
     }
 }
 
 pub enum EffectDurationTicks {
     Instant,
     DurationTicks(ModifierMagnitude),
+    /// Converted to fixed ticks with [`seconds_to_ticks`] when the spec is made.
+    DurationSeconds(ModifierMagnitude),
     Infinite,
 }
 
 impl EffectDurationTicks {
+    /// `None` for `Instant` and `Infinite`.
+    pub fn get_magnitude(&self) -> Option<&ModifierMagnitude> {
+        match self {
+            EffectDurationTicks::DurationTicks(mm) | EffectDurationTicks::DurationSeconds(mm) => {
+                Some(mm)
+            }
+            EffectDurationTicks::Instant | EffectDurationTicks::Infinite => None,
+        }
+    }
+
     pub fn make_spec(&self, context: &EffectContext) -> EffectDurationTicksSpec {
         match self {
             EffectDurationTicks::Instant => EffectDurationTicksSpec::Instant,
             EffectDurationTicks::DurationTicks(mm) => {
-                EffectDurationTicksSpec::DurationTicks(magnitude_to_ticks(match mm {
-                    ModifierMagnitude::Flat(f) => *f,
-                    ModifierMagnitude::Calculated(mmc) => mmc.calculate(context),
-                }))
+                EffectDurationTicksSpec::DurationTicks(magnitude_to_ticks(mm.calculate(context)))
             }
+            EffectDurationTicks::DurationSeconds(mm) => EffectDurationTicksSpec::DurationTicks(
+                seconds_to_ticks(mm.calculate(context), context.fixed_timestep),
+            ),
             EffectDurationTicks::Infinite => EffectDurationTicksSpec::Infinite,
         }
     }
 }
 
+/// Unit the period magnitude is authored in, like `DurationTicks` / `DurationSeconds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum EffectPeriodUnit {
+    #[default]
+    Ticks,
+    /// Converted to fixed ticks with [`seconds_to_ticks`] when the spec is made.
+    Seconds,
+}
+
 pub struct EffectPeriodTicks {
-    period_ticks: ModifierMagnitude,
+    period: ModifierMagnitude,
+    unit: EffectPeriodUnit,
     execute_on_applied: bool,
 }
 
 impl EffectPeriodTicks {
     pub fn new(period_ticks: ModifierMagnitude, execute_on_applied: bool) -> Self {
         Self {
-            period_ticks,
+            period: period_ticks,
+            unit: EffectPeriodUnit::Ticks,
             execute_on_applied,
         }
     }
 
+    /// Period authored in seconds, converted with [`seconds_to_ticks`] when the spec is made.
+    pub fn from_seconds(period_seconds: ModifierMagnitude, execute_on_applied: bool) -> Self {
+        Self::new(period_seconds, execute_on_applied).with_unit(EffectPeriodUnit::Seconds)
+    }
+
+    pub fn with_unit(mut self, unit: EffectPeriodUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn get_unit(&self) -> EffectPeriodUnit {
+        self.unit
+    }
+
+    pub fn get_magnitude(&self) -> &ModifierMagnitude {
+        &self.period
+    }
+
     pub fn make_spec(&self, context: &EffectContext) -> EffectPeriodTicksSpec {
-        let final_value = match &self.period_ticks {
-            ModifierMagnitude::Flat(f) => *f,
-            ModifierMagnitude::Calculated(mmc) => mmc.calculate(context),
+        let value = self.period.calculate(context);
+        let final_value = match self.unit {
+            EffectPeriodUnit::Ticks => magnitude_to_ticks(value),
+            EffectPeriodUnit::Seconds => seconds_to_ticks(value, context.fixed_timestep),
         };
-        let final_value = magnitude_to_ticks(final_value);
         EffectPeriodTicksSpec::new(final_value, self.execute_on_applied)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Converts authored seconds to fixed ticks, rounding up so an effect never ends before its
+/// authored time: 1.0s at a 0.3s timestep lasts 4 ticks (1.2s).
+///
+/// Quotients within `1e-6` of a whole tick count are treated as whole, so 1.0s at 1/60s is
+/// 60 ticks despite float error. Non-positive or non-finite values give 0 ticks, like tick
+/// magnitudes, and a zero timestep gives 0 ticks as well.
+pub fn seconds_to_ticks(seconds: f64, fixed_timestep: Duration) -> u32 {
+    const WHOLE_TICK_TOLERANCE: f64 = 1e-6;
+
+    let timestep = fixed_timestep.as_secs_f64();
+    if timestep <= 0.0 {
+        return 0;
+    }
+    let ticks = seconds / timestep;
+    let nearest = ticks.round();
+    if (ticks - nearest).abs() <= WHOLE_TICK_TOLERANCE {
+        magnitude_to_ticks(nearest)
+    } else {
+        magnitude_to_ticks(ticks)
+    }
+}
+
+pub fn ticks_to_seconds(ticks: u32, fixed_timestep: Duration) -> f64 {
+    ticks as f64 * fixed_timestep.as_secs_f64()
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub enum StackingType {
     None,
     AggregateBySource,
     AggregateByTarget,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// How the stack count scales modifier magnitudes, for duration modifiers and periodic
+/// instant modifiers alike.
+#[derive(Clone)]
 pub enum StackMagnitudePolicy {
+    /// Every stack count applies the magnitude once.
     None,
+    /// The magnitude is multiplied by the stack count.
     Linear,
+    /// The first stack applies the full magnitude and each additional stack adds
+    /// `additional_stack_scale` of it, e.g. `0.5` turns a 10% modifier into 10%, 15%, 20%.
+    Diminishing {
+        additional_stack_scale: f64,
+    },
+    /// The magnitude is multiplied by the curve sampled at the stack count.
+    Curve(Arc<RealCurve>),
+    Custom(Arc<dyn StackMagnitudeCalculation>),
+}
+
+impl StackMagnitudePolicy {
+    pub fn get_multiplier(&self, stack_count: u32) -> f64 {
+        match self {
+            StackMagnitudePolicy::None => 1.0,
+            StackMagnitudePolicy::Linear => stack_count as f64,
+            StackMagnitudePolicy::Diminishing {
+                additional_stack_scale,
+            } => 1.0 + stack_count.saturating_sub(1) as f64 * additional_stack_scale,
+            StackMagnitudePolicy::Curve(curve) => curve.evaluate(stack_count as f64),
+            StackMagnitudePolicy::Custom(calculation) => calculation.calculate(stack_count),
+        }
+    }
+}
+
+impl fmt::Debug for StackMagnitudePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StackMagnitudePolicy::None => write!(f, "None"),
+            StackMagnitudePolicy::Linear => write!(f, "Linear"),
+            StackMagnitudePolicy::Diminishing {
+                additional_stack_scale,
+            } => f
+                .debug_struct("Diminishing")
+                .field("additional_stack_scale", additional_stack_scale)
+                .finish(),
+            StackMagnitudePolicy::Curve(curve) => f.debug_tuple("Curve").field(curve).finish(),
+            StackMagnitudePolicy::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Magnitude multiplier for a stack count, for stacking rules no built-in policy covers.
+pub trait StackMagnitudeCalculation: Send + Sync {
+    fn calculate(&self, stack_count: u32) -> f64;
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub enum StackDurationPolicy {
     KeepExisting,
     RefreshOnSuccessfulStack,
+    /// Each stack keeps its own timer and expires on its own, whatever the expiration policy.
+    IndependentPerStack,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub enum StackPeriodPolicy {
     KeepCurrentTick,
     ResetOnSuccessfulStack,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// What an application at the stack limit does to the existing effect. Overflow effects are
+/// queued either way.
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub enum StackOverflowPolicy {
+    /// Deny the application. Without overflow effects it fails with `StackLimitReached`.
     RejectApplication,
     RefreshDuration,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub enum StackExpirationPolicy {
     RemoveAllStacks,
     RemoveSingleStack,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct StackingPolicy {
     stacking_type: StackingType,
     stack_limit: u32,
@@ -169,6 +435,7 @@ pub struct StackingPolicy {
     period_policy: StackPeriodPolicy,
     overflow_policy: StackOverflowPolicy,
     expiration_policy: StackExpirationPolicy,
+    clear_stacks_on_overflow: bool,
 }
 
 impl StackingPolicy {
@@ -189,9 +456,17 @@ impl StackingPolicy {
             period_policy,
             overflow_policy,
             expiration_policy,
+            clear_stacks_on_overflow: false,
         }
     }
 
+    /// Removes the existing effect when an application overflows it, after queuing the
+    /// overflow effects, e.g. five stacks of Chill are consumed to apply Frozen.
+    pub fn with_clear_stacks_on_overflow(mut self) -> Self {
+        self.clear_stacks_on_overflow = true;
+        self
+    }
+
     pub fn non_stacking() -> Self {
         Self::new(
             StackingType::None,
@@ -224,8 +499,8 @@ impl StackingPolicy {
         self.stack_limit
     }
 
-    pub fn get_magnitude_policy(&self) -> StackMagnitudePolicy {
-        self.magnitude_policy
+    pub fn get_magnitude_policy(&self) -> &StackMagnitudePolicy {
+        &self.magnitude_policy
     }
 
     pub fn get_duration_policy(&self) -> StackDurationPolicy {
@@ -243,6 +518,10 @@ impl StackingPolicy {
     pub fn get_expiration_policy(&self) -> StackExpirationPolicy {
         self.expiration_policy
     }
+
+    pub fn is_clear_stacks_on_overflow(&self) -> bool {
+        self.clear_stacks_on_overflow
+    }
 }
 
 #[derive(Default)]
@@ -462,6 +741,86 @@ impl EffectTags {
     }
 }
 
+/// Effect queued on the same target after the owning effect applies successfully, if the source
+/// tags pass `source_tags` at that moment.
+pub struct ConditionalGameplayEffect {
+    effect: Arc<GameplayEffect>,
+    source_tags: TagRequirements,
+}
+
+impl ConditionalGameplayEffect {
+    pub fn new(effect: Arc<GameplayEffect>, source_tags: TagRequirements) -> Self {
+        Self {
+            effect,
+            source_tags,
+        }
+    }
+
+    pub fn get_effect(&self) -> &Arc<GameplayEffect> {
+        &self.effect
+    }
+
+    pub fn get_source_tags(&self) -> &TagRequirements {
+        &self.source_tags
+    }
+}
+
+/// What happens to a granted ability that is still running when the granting effect is removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GrantedAbilityRemovalPolicy {
+    /// Cancel running instances and remove the ability right away.
+    #[default]
+    CancelImmediately,
+    /// Let running instances finish; the ability can no longer be activated and is removed once
+    /// the last instance ends.
+    RemoveOnEnd,
+}
+
+/// Ability given to the target's [`AbilitySystemComponent`] while the owning active effect exists.
+pub struct GameplayEffectGrantedAbility {
+    ability: Arc<GameplayAbility>,
+    level: u32,
+    input_id: Option<u16>,
+    removal_policy: GrantedAbilityRemovalPolicy,
+}
+
+impl GameplayEffectGrantedAbility {
+    pub fn new(ability: Arc<GameplayAbility>, level: u32) -> Self {
+        Self {
+            ability,
+            level,
+            input_id: None,
+            removal_policy: GrantedAbilityRemovalPolicy::default(),
+        }
+    }
+
+    pub fn with_input_id(mut self, input_id: u16) -> Self {
+        self.input_id = Some(input_id);
+        self
+    }
+
+    pub fn with_removal_policy(mut self, removal_policy: GrantedAbilityRemovalPolicy) -> Self {
+        self.removal_policy = removal_policy;
+        self
+    }
+
+    pub fn get_ability(&self) -> &Arc<GameplayAbility> {
+        &self.ability
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn get_input_id(&self) -> Option<u16> {
+        self.input_id
+    }
+
+    pub fn get_removal_policy(&self) -> GrantedAbilityRemovalPolicy {
+        self.removal_policy
+    }
+}
+
 // stored as a Resource
 pub struct GameplayEffect {
     modifiers: Vec<Modifier>,
@@ -470,6 +829,11 @@ pub struct GameplayEffect {
     probability_to_apply: f64,
     stacking_policy: StackingPolicy,
     tags: EffectTags,
+    executions: Vec<Box<dyn GameplayEffectExecutionCalculation>>,
+    conditional_effects: Vec<ConditionalGameplayEffect>,
+    granted_abilities: Vec<GameplayEffectGrantedAbility>,
+    overflow_effects: Vec<Arc<GameplayEffect>>,
+    def_key: Option<UniqueName>,
 }
 
 impl GameplayEffect {
@@ -488,9 +852,56 @@ impl GameplayEffect {
             probability_to_apply,
             stacking_policy,
             tags,
+            executions: Vec::new(),
+            conditional_effects: Vec::new(),
+            granted_abilities: Vec::new(),
+            overflow_effects: Vec::new(),
+            def_key: None,
         }
     }
 
+    /// Identity shared by every resolution of the same definition, e.g. its asset path. Effects
+    /// with the same key stack as one definition even when they are different `Arc`s, such as
+    /// before and after a hot reload.
+    pub fn with_def_key(mut self, def_key: UniqueName) -> Self {
+        self.def_key = Some(def_key);
+        self
+    }
+
+    pub fn get_def_key(&self) -> Option<UniqueName> {
+        self.def_key
+    }
+
+    /// Adds an execution; see [`GameplayEffectExecutionCalculation`].
+    pub fn with_execution(
+        mut self,
+        execution: impl GameplayEffectExecutionCalculation + 'static,
+    ) -> Self {
+        self.executions.push(Box::new(execution));
+        self
+    }
+
+    pub fn with_conditional_effect(
+        mut self,
+        conditional_effect: ConditionalGameplayEffect,
+    ) -> Self {
+        self.conditional_effects.push(conditional_effect);
+        self
+    }
+
+    /// Grants `granted_ability` while the active effect exists. Instant effects grant nothing.
+    pub fn with_granted_ability(mut self, granted_ability: GameplayEffectGrantedAbility) -> Self {
+        self.granted_abilities.push(granted_ability);
+        self
+    }
+
+    /// Queued on the target when an application arrives at the stack limit, see
+    /// [`StackOverflowPolicy`].
+    pub fn with_overflow_effect(mut self, overflow_effect: Arc<GameplayEffect>) -> Self {
+        self.overflow_effects.push(overflow_effect);
+        self
+    }
+
     pub fn make_spec(self: &Arc<Self>, context: &EffectContext) -> GameplayEffectSpec {
         GameplayEffectSpec::new(
             self.clone(),
@@ -500,14 +911,65 @@ impl GameplayEffect {
                 .collect(),
             self.duration.make_spec(context),
             self.period.as_ref().map(|p| p.make_spec(context)),
-            self.stacking_policy,
+            self.stacking_policy.clone(),
         )
     }
 
+    /// Runs every execution against `spec` and merges their outputs in order.
+    pub fn execute(
+        &self,
+        context: &EffectContext,
+        spec: &GameplayEffectSpec,
+        stack_count: u32,
+        random: &mut Random,
+    ) -> GameplayEffectExecutionOutput {
+        let mut output = GameplayEffectExecutionOutput::new();
+        let mut params = GameplayEffectExecutionParams::new(context, spec, stack_count, random);
+        for execution in &self.executions {
+            output.append(execution.execute(&mut params));
+        }
+        output
+    }
+
     pub fn get_tags(&self) -> &EffectTags {
         &self.tags
     }
 
+    pub fn get_executions(&self) -> &[Box<dyn GameplayEffectExecutionCalculation>] {
+        &self.executions
+    }
+
+    pub fn get_conditional_effects(&self) -> &[ConditionalGameplayEffect] {
+        &self.conditional_effects
+    }
+
+    pub fn get_modifiers(&self) -> &[Modifier] {
+        &self.modifiers
+    }
+
+    pub fn get_period(&self) -> Option<&EffectPeriodTicks> {
+        self.period.as_ref()
+    }
+
+    pub fn get_granted_abilities(&self) -> &[GameplayEffectGrantedAbility] {
+        &self.granted_abilities
+    }
+
+    pub fn get_overflow_effects(&self) -> &[Arc<GameplayEffect>] {
+        &self.overflow_effects
+    }
+
+    /// First set-by-caller key read by a modifier, the duration or the period that rejects
+    /// and has no value in `payload`.
+    pub fn find_missing_set_by_caller(&self, payload: &EffectPayload) -> Option<SetByCallerKey> {
+        self.modifiers
+            .iter()
+            .map(Modifier::get_magnitude)
+            .chain(self.duration.get_magnitude())
+            .chain(self.period.as_ref().map(EffectPeriodTicks::get_magnitude))
+            .find_map(|magnitude| magnitude.find_missing_set_by_caller(payload))
+    }
+
     pub fn has_only_add_modifiers(&self) -> bool {
         self.modifiers
             .iter()
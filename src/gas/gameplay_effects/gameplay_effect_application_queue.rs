@@ -101,7 +101,8 @@ pub fn process_gameplay_effect_application_queue_system(
             return;
         };
 
-        apply_gameplay_effect(
+        // Failures are reported through `GameplayEffectRejectedEvent`; queued requests have no caller.
+        let _ = apply_gameplay_effect(
             request.get_target(),
             request.get_effect(),
             &mut params,
@@ -0,0 +1,123 @@
+use super::{ActiveEffectHandle, EffectPayload};
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameplayEffectLifecycle {
+    /// An instant effect executed, or a new active effect was created.
+    Applied,
+    Stacked {
+        stack_count: u32,
+    },
+    /// Stacks were removed by the API; `stack_count` is the number left.
+    Unstacked {
+        stack_count: u32,
+    },
+    /// Re-applied at the stack limit with `StackOverflowPolicy::RefreshDuration`.
+    Refreshed,
+    /// Re-applied at the stack limit of an effect with overflow effects or
+    /// `clear_stacks_on_overflow`; the overflow effects were queued.
+    Overflowed,
+    /// A periodic effect executed on its period tick.
+    Executed,
+    Inhibited,
+    Uninhibited,
+    /// The spec was re-made from a reloaded definition.
+    Rebuilt,
+    /// Removed before its duration ran out, by removal requirements, tags or the API.
+    Removed,
+    Expired,
+}
+
+/// Fired on the target for every lifecycle step of an effect applied to it.
+#[derive(EntityEvent, Clone)]
+pub struct GameplayEffectLifecycleEvent {
+    entity: Entity,
+    handle: Option<ActiveEffectHandle>,
+    lifecycle: GameplayEffectLifecycle,
+    payload: EffectPayload,
+}
+
+impl GameplayEffectLifecycleEvent {
+    pub fn new(
+        entity: Entity,
+        handle: Option<ActiveEffectHandle>,
+        lifecycle: GameplayEffectLifecycle,
+        payload: EffectPayload,
+    ) -> Self {
+        Self {
+            entity,
+            handle,
+            lifecycle,
+            payload,
+        }
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// `None` for instant effects, which have no active effect entity.
+    pub fn get_handle(&self) -> Option<ActiveEffectHandle> {
+        self.handle
+    }
+
+    pub fn get_lifecycle(&self) -> GameplayEffectLifecycle {
+        self.lifecycle
+    }
+
+    pub fn get_payload(&self) -> &EffectPayload {
+        &self.payload
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameplayEffectRejectionReason {
+    Probability,
+    SourceRequirements,
+    TargetRequirements,
+    Immunity,
+    StackOverflow,
+}
+
+/// Fired on the target when an application is rejected before anything changed. The one
+/// exception is a rejected overflow, which still queues the effect's overflow effects.
+#[derive(EntityEvent, Clone)]
+pub struct GameplayEffectRejectedEvent {
+    entity: Entity,
+    handle: Option<ActiveEffectHandle>,
+    reason: GameplayEffectRejectionReason,
+    payload: EffectPayload,
+}
+
+impl GameplayEffectRejectedEvent {
+    pub fn new(
+        entity: Entity,
+        handle: Option<ActiveEffectHandle>,
+        reason: GameplayEffectRejectionReason,
+        payload: EffectPayload,
+    ) -> Self {
+        Self {
+            entity,
+            handle,
+            reason,
+            payload,
+        }
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// The active effect that rejected the application, set for `StackOverflow`.
+    pub fn get_handle(&self) -> Option<ActiveEffectHandle> {
+        self.handle
+    }
+
+    pub fn get_reason(&self) -> GameplayEffectRejectionReason {
+        self.reason
+    }
+
+    pub fn get_payload(&self) -> &EffectPayload {
+        &self.payload
+    }
+}
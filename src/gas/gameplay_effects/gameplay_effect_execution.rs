@@ -0,0 +1,149 @@
+use super::{EffectContext, GameplayEffectSpec};
+use crate::Random;
+use crate::attributes::AttributeId;
+use crate::gameplay_tags::GameplayTag;
+use crate::modifiers::{AttributeCaptureSide, AttributeCaptureValue, ModifierSpec};
+use bevy::prelude::*;
+
+/// Custom calculation that turns one application of an effect into any number of modifiers,
+/// e.g. damage that reads attack and armor, rolls a critical hit and writes health and shield.
+///
+/// Executions run each time an instant or periodic effect executes, after the application has
+/// passed every check, so a periodic effect rolls again on every period. Their outputs are applied
+/// like instant modifiers and scaled by the stack magnitude policy. Effects with executions that
+/// are neither instant nor periodic are rejected with `EffectApplicationError::InvalidSpec`.
+pub trait GameplayEffectExecutionCalculation: Send + Sync {
+    fn execute(&self, params: &mut GameplayEffectExecutionParams) -> GameplayEffectExecutionOutput;
+}
+
+pub struct GameplayEffectExecutionParams<'a, 'w, 's> {
+    context: &'a EffectContext<'w, 's>,
+    spec: &'a GameplayEffectSpec,
+    stack_count: u32,
+    random: &'a mut Random,
+}
+
+impl<'a, 'w, 's> GameplayEffectExecutionParams<'a, 'w, 's> {
+    pub fn new(
+        context: &'a EffectContext<'w, 's>,
+        spec: &'a GameplayEffectSpec,
+        stack_count: u32,
+        random: &'a mut Random,
+    ) -> Self {
+        Self {
+            context,
+            spec,
+            stack_count,
+            random,
+        }
+    }
+
+    pub fn get_context(&self) -> &EffectContext<'w, 's> {
+        self.context
+    }
+
+    /// Spec with the effect's own modifier magnitudes already calculated.
+    pub fn get_spec(&self) -> &GameplayEffectSpec {
+        self.spec
+    }
+
+    /// Stack count of the effect at the moment it executes; 1 for instant effects.
+    pub fn get_stack_count(&self) -> u32 {
+        self.stack_count
+    }
+
+    pub fn get_random(&mut self) -> &mut Random {
+        self.random
+    }
+
+    /// Same capture rules as `AttributeBasedMagnitude`; `None` when the attribute is missing.
+    pub fn capture_attribute(
+        &self,
+        attribute: AttributeId,
+        side: AttributeCaptureSide,
+        snapshot: bool,
+        value: AttributeCaptureValue,
+    ) -> Option<f64> {
+        self.context
+            .capture_attribute(attribute, side, snapshot, value)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GameplayEffectExecutionOutput {
+    modifiers: Vec<ModifierSpec>,
+    tags: Vec<GameplayTag>,
+}
+
+impl GameplayEffectExecutionOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_modifier(mut self, modifier: ModifierSpec) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    /// Tags describing the result, e.g. `Result.Critical`, reported in [`GameplayEffectExecutedEvent`].
+    pub fn with_tag(mut self, tag: GameplayTag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    pub fn add_modifier(&mut self, modifier: ModifierSpec) {
+        self.modifiers.push(modifier);
+    }
+
+    pub fn add_tag(&mut self, tag: GameplayTag) {
+        self.tags.push(tag);
+    }
+
+    pub fn get_modifiers(&self) -> &[ModifierSpec] {
+        &self.modifiers
+    }
+
+    pub fn get_tags(&self) -> &[GameplayTag] {
+        &self.tags
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modifiers.is_empty() && self.tags.is_empty()
+    }
+
+    pub(crate) fn append(&mut self, other: GameplayEffectExecutionOutput) {
+        self.modifiers.extend(other.modifiers);
+        self.tags.extend(other.tags);
+    }
+}
+
+/// Fired on the target each time the execution outputs of an effect are applied to it.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct GameplayEffectExecutedEvent {
+    entity: Entity,
+    source: Entity,
+    output: GameplayEffectExecutionOutput,
+}
+
+impl GameplayEffectExecutedEvent {
+    pub fn new(entity: Entity, source: Entity, output: GameplayEffectExecutionOutput) -> Self {
+        Self {
+            entity,
+            source,
+            output,
+        }
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    pub fn get_source(&self) -> Entity {
+        self.source
+    }
+
+    /// Modifiers as applied for one stack, and the result tags.
+    pub fn get_output(&self) -> &GameplayEffectExecutionOutput {
+        &self.output
+    }
+}
@@ -73,8 +73,17 @@ impl GameplayEffectSpec {
         }
     }
 
+    /// Same `Arc`, or the same [`GameplayEffect::get_def_key`].
     pub fn is_same_def(&self, other: &GameplayEffectSpec) -> bool {
         Arc::ptr_eq(&self.def, &other.def)
+            || self
+                .def
+                .get_def_key()
+                .is_some_and(|key| other.def.get_def_key() == Some(key))
+    }
+
+    pub fn get_def(&self) -> &Arc<GameplayEffect> {
+        &self.def
     }
 
     pub fn get_def_tags(&self) -> &EffectTags {
@@ -97,7 +106,7 @@ impl GameplayEffectSpec {
         &self.period_spec
     }
 
-    pub fn get_stacking_policy(&self) -> StackingPolicy {
-        self.stacking_policy
+    pub fn get_stacking_policy(&self) -> &StackingPolicy {
+        &self.stacking_policy
     }
 }
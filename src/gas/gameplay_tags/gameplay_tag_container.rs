@@ -40,7 +40,7 @@ pub fn add_bit_with_tag(bits: &mut GameplayTagBits, tag: &GameplayTag) -> Option
     Some(())
 }
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct GameplayTagContainer {
     tag_bits: GameplayTagBits,
     ref_counts: Box<[u16]>,
@@ -125,6 +125,11 @@ impl GameplayTagContainer {
             self.remove_tag(tag, manager);
         }
     }
+
+    pub fn get_tag_bits(&self) -> &GameplayTagBits {
+        &self.tag_bits
+    }
+
     pub fn has_tag(&self, tag: &GameplayTag) -> bool {
         let tag_bit_index = tag.get_bit_index_usize();
         if tag_bit_index >= MAX_TAG_COUNTS {
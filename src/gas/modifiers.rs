@@ -1,3 +1,7 @@
+mod attribute_based_magnitude;
 mod modifier;
+mod set_by_caller_magnitude;
 
+pub use attribute_based_magnitude::*;
 pub use modifier::*;
+pub use set_by_caller_magnitude::*;
@@ -0,0 +1,93 @@
+use crate::attributes::AttributeId;
+use crate::gameplay_effects::EffectContext;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AttributeCaptureSide {
+    Source,
+    Target,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AttributeCaptureValue {
+    Base,
+    Current,
+    /// `current - base`, i.e. everything contributed by duration modifiers and clamping.
+    Bonus,
+}
+
+/// Captures one attribute of the source or target and turns it into
+/// `coefficient * (value + pre_multiply_additive) + post_multiply_additive`.
+///
+/// With `snapshot` set, source attributes are read from `EffectPayload::source_snapshot` when the
+/// payload carries one; otherwise, and for the target side, the live value at spec creation is used.
+/// A missing attribute reads as `0.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeBasedMagnitude {
+    attribute: AttributeId,
+    side: AttributeCaptureSide,
+    snapshot: bool,
+    value: AttributeCaptureValue,
+    coefficient: f64,
+    pre_multiply_additive: f64,
+    post_multiply_additive: f64,
+}
+
+impl AttributeBasedMagnitude {
+    pub fn new(
+        attribute: AttributeId,
+        side: AttributeCaptureSide,
+        snapshot: bool,
+        value: AttributeCaptureValue,
+    ) -> Self {
+        Self {
+            attribute,
+            side,
+            snapshot,
+            value,
+            coefficient: 1.0,
+            pre_multiply_additive: 0.0,
+            post_multiply_additive: 0.0,
+        }
+    }
+
+    pub fn with_coefficient(mut self, coefficient: f64) -> Self {
+        self.coefficient = coefficient;
+        self
+    }
+
+    pub fn with_pre_multiply_additive(mut self, pre_multiply_additive: f64) -> Self {
+        self.pre_multiply_additive = pre_multiply_additive;
+        self
+    }
+
+    pub fn with_post_multiply_additive(mut self, post_multiply_additive: f64) -> Self {
+        self.post_multiply_additive = post_multiply_additive;
+        self
+    }
+
+    pub fn get_attribute(&self) -> AttributeId {
+        self.attribute
+    }
+
+    pub fn get_side(&self) -> AttributeCaptureSide {
+        self.side
+    }
+
+    pub fn is_snapshot(&self) -> bool {
+        self.snapshot
+    }
+
+    pub fn get_value(&self) -> AttributeCaptureValue {
+        self.value
+    }
+
+    pub fn calculate(&self, context: &EffectContext) -> f64 {
+        let captured = self.capture(context).unwrap_or(0.0);
+        self.coefficient * (captured + self.pre_multiply_additive) + self.post_multiply_additive
+    }
+
+    fn capture(&self, context: &EffectContext) -> Option<f64> {
+        context.capture_attribute(self.attribute, self.side, self.snapshot, self.value)
+    }
+}
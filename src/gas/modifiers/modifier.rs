@@ -1,7 +1,11 @@
+use super::{AttributeBasedMagnitude, SetByCallerKey, SetByCallerMagnitude};
 use crate::attributes::AttributeId;
-use crate::gameplay_effects::{ActiveEffectHandle, EffectContext};
+use crate::curves::ScalableFloat;
+use crate::gameplay_effects::{ActiveEffectHandle, EffectContext, EffectPayload, TagRequirements};
+use crate::gameplay_tags::GameplayTagContainer;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum ModifierOperation {
     Add,
     PercentAdd,
@@ -12,6 +16,35 @@ pub enum ModifierOperation {
 pub enum ModifierMagnitude {
     Flat(f64),
     Calculated(Box<dyn ModifierMagnitudeCalculation>),
+    ScalableFloat(ScalableFloat),
+    AttributeBased(AttributeBasedMagnitude),
+    SetByCaller(SetByCallerMagnitude),
+}
+
+impl ModifierMagnitude {
+    pub fn calculate(&self, context: &EffectContext) -> f64 {
+        match self {
+            ModifierMagnitude::Flat(value) => *value,
+            ModifierMagnitude::Calculated(calc) => calc.calculate(context),
+            ModifierMagnitude::ScalableFloat(scalable) => {
+                scalable.get_value_at_level(context.level())
+            }
+            ModifierMagnitude::AttributeBased(attribute_based) => {
+                attribute_based.calculate(context)
+            }
+            ModifierMagnitude::SetByCaller(set_by_caller) => set_by_caller.calculate(context),
+        }
+    }
+
+    /// Key of a set-by-caller magnitude that rejects and has no value in `payload`.
+    pub fn find_missing_set_by_caller(&self, payload: &EffectPayload) -> Option<SetByCallerKey> {
+        match self {
+            ModifierMagnitude::SetByCaller(set_by_caller) => set_by_caller
+                .is_missing(payload)
+                .then_some(set_by_caller.get_key()),
+            _ => None,
+        }
+    }
 }
 
 pub trait ModifierMagnitudeCalculation: Send + Sync {
@@ -22,22 +55,68 @@ pub struct Modifier {
     id: AttributeId,
     op: ModifierOperation,
     magnitude: ModifierMagnitude,
+    source_tags: TagRequirements,
+    target_tags: TagRequirements,
 }
 
 impl Modifier {
     pub fn new(id: AttributeId, op: ModifierOperation, magnitude: ModifierMagnitude) -> Self {
-        Modifier { id, op, magnitude }
+        Modifier {
+            id,
+            op,
+            magnitude,
+            source_tags: TagRequirements::default(),
+            target_tags: TagRequirements::default(),
+        }
+    }
+
+    /// Only applies while the effect source matches `source_tags`; checked on application
+    /// and re-checked every tag requirement update for active effects.
+    pub fn with_source_tag_requirements(mut self, source_tags: TagRequirements) -> Self {
+        self.source_tags = source_tags;
+        self
+    }
+
+    /// Only applies while the effect target matches `target_tags`.
+    pub fn with_target_tag_requirements(mut self, target_tags: TagRequirements) -> Self {
+        self.target_tags = target_tags;
+        self
+    }
+
+    pub fn get_id(&self) -> AttributeId {
+        self.id
     }
 
     pub fn get_operation(&self) -> ModifierOperation {
         self.op
     }
 
+    pub fn get_magnitude(&self) -> &ModifierMagnitude {
+        &self.magnitude
+    }
+
+    pub fn get_source_tag_requirements(&self) -> &TagRequirements {
+        &self.source_tags
+    }
+
+    pub fn get_target_tag_requirements(&self) -> &TagRequirements {
+        &self.target_tags
+    }
+
+    pub fn has_tag_requirements(&self) -> bool {
+        !self.source_tags.is_empty() || !self.target_tags.is_empty()
+    }
+
+    pub fn passes_tag_requirements(
+        &self,
+        source_tags: Option<&GameplayTagContainer>,
+        target_tags: Option<&GameplayTagContainer>,
+    ) -> bool {
+        self.source_tags.passes(source_tags) && self.target_tags.passes(target_tags)
+    }
+
     pub fn make_spec(&self, context: &EffectContext) -> ModifierSpec {
-        let final_value = match &self.magnitude {
-            ModifierMagnitude::Flat(value) => *value,
-            ModifierMagnitude::Calculated(calc) => calc.calculate(context),
-        };
+        let final_value = self.magnitude.calculate(context);
 
         ModifierSpec {
             id: self.id,
@@ -55,6 +134,10 @@ pub struct ModifierSpec {
 }
 
 impl ModifierSpec {
+    pub fn new(id: AttributeId, op: ModifierOperation, value: f64) -> Self {
+        Self { id, op, value }
+    }
+
     pub fn get_id(&self) -> AttributeId {
         self.id
     }
@@ -68,10 +151,15 @@ impl ModifierSpec {
     }
 
     pub fn scaled_by_stack(&self, stack_count: u32) -> Self {
+        self.scaled_by(stack_count as f64)
+    }
+
+    /// Scales the value by an arbitrary multiplier, e.g. from a `StackMagnitudePolicy`.
+    pub fn scaled_by(&self, multiplier: f64) -> Self {
         Self {
             id: self.id,
             op: self.op,
-            value: self.value * stack_count as f64,
+            value: self.value * multiplier,
         }
     }
 }
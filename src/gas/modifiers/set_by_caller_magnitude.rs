@@ -0,0 +1,88 @@
+use crate::gameplay_effects::{EffectContext, EffectPayload};
+use crate::gameplay_tags::GameplayTag;
+use crate::unique_names::UniqueName;
+use std::fmt;
+
+/// Key of a value the caller stores on [`EffectPayload`] before applying an effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetByCallerKey {
+    Name(UniqueName),
+    Tag(GameplayTag),
+}
+
+impl From<UniqueName> for SetByCallerKey {
+    fn from(name: UniqueName) -> Self {
+        SetByCallerKey::Name(name)
+    }
+}
+
+impl From<GameplayTag> for SetByCallerKey {
+    fn from(tag: GameplayTag) -> Self {
+        SetByCallerKey::Tag(tag)
+    }
+}
+
+impl fmt::Display for SetByCallerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetByCallerKey::Name(name) => write!(f, "{name:?}"),
+            SetByCallerKey::Tag(tag) => write!(f, "{tag:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetByCallerMissingPolicy {
+    /// Reject the application with `EffectApplicationError::MissingSetByCallerMagnitude`.
+    Reject,
+    Fallback(f64),
+}
+
+/// Reads a value the caller set on the payload, e.g. charged-shot damage from the hold time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetByCallerMagnitude {
+    key: SetByCallerKey,
+    missing_policy: SetByCallerMissingPolicy,
+}
+
+impl SetByCallerMagnitude {
+    /// Rejects the application when the payload has no value for `key`.
+    pub fn new(key: impl Into<SetByCallerKey>) -> Self {
+        Self {
+            key: key.into(),
+            missing_policy: SetByCallerMissingPolicy::Reject,
+        }
+    }
+
+    /// Uses `fallback` instead of rejecting when the payload has no value for the key.
+    pub fn with_fallback(mut self, fallback: f64) -> Self {
+        self.missing_policy = SetByCallerMissingPolicy::Fallback(fallback);
+        self
+    }
+
+    pub fn get_key(&self) -> SetByCallerKey {
+        self.key
+    }
+
+    pub fn get_missing_policy(&self) -> SetByCallerMissingPolicy {
+        self.missing_policy
+    }
+
+    /// `true` when applying with `payload` must be rejected.
+    pub fn is_missing(&self, payload: &EffectPayload) -> bool {
+        matches!(self.missing_policy, SetByCallerMissingPolicy::Reject)
+            && payload.get_set_by_caller_magnitude(self.key).is_none()
+    }
+
+    /// A missing key with [`SetByCallerMissingPolicy::Reject`] reads as `0.0`; prepare rejects
+    /// such applications before any magnitude is calculated.
+    pub fn calculate(&self, context: &EffectContext) -> f64 {
+        context
+            .payload
+            .get_set_by_caller_magnitude(self.key)
+            .unwrap_or(match self.missing_policy {
+                SetByCallerMissingPolicy::Reject => 0.0,
+                SetByCallerMissingPolicy::Fallback(fallback) => fallback,
+            })
+    }
+}
@@ -6,4 +6,5 @@ impl GameplayAbilitySystemSettings {
     pub const ABILITY_ACTIVATION_QUEUE_MAX_PER_TICK: usize = 64;
     pub const GAMEPLAY_EFFECT_APPLICATION_QUEUE_MAX_PER_TICK: usize = 64;
     pub const ABILITY_CHAIN_MAX_DEPTH: u8 = 8;
+    pub const GAMEPLAY_EFFECT_CHAIN_MAX_DEPTH: u8 = 8;
 }
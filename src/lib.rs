@@ -4,6 +4,7 @@ mod unique_names;
 
 use bevy::app::PluginGroupBuilder;
 use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
 pub use gas::*;
 pub use randoms::*;
 pub use unique_names::*;
@@ -38,6 +39,7 @@ pub struct GameplayAbilitySystemRuntimePlugin;
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum GameplayAbilitySystemSet {
     UpdateEffectTagRequirements,
+    AdvanceTime,
     EffectTicks,
     AbilityTasks,
     Queues,
@@ -47,7 +49,14 @@ pub enum GameplayAbilitySystemSet {
 
 impl Plugin for GameplayAbilitySystemRuntimePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AttributeIdManager>()
+        // Add `DefaultPlugins` before this plugin, otherwise it installs `StatesPlugin` itself.
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        app.init_state::<GameplayAbilitySystemState>()
+            .init_resource::<AttributeIdManager>()
+            .init_resource::<AttributeHistoryClock>()
             .init_resource::<AbilityActivationQueue>()
             .init_resource::<GameplayEffectApplicationQueue>()
             .init_resource::<ActiveGameplayEffectTargetIndex>()
@@ -56,6 +65,15 @@ impl Plugin for GameplayAbilitySystemRuntimePlugin {
                 (
                     GameplayAbilitySystemSet::UpdateEffectTagRequirements
                         .before(GameplayAbilitySystemSet::EffectTicks),
+                    GameplayAbilitySystemSet::AdvanceTime
+                        .before(GameplayAbilitySystemSet::EffectTicks)
+                        .before(GameplayAbilitySystemSet::AbilityTasks),
+                    (
+                        GameplayAbilitySystemSet::AdvanceTime,
+                        GameplayAbilitySystemSet::EffectTicks,
+                        GameplayAbilitySystemSet::AbilityTasks,
+                    )
+                        .run_if(in_state(GameplayAbilitySystemState::Running)),
                     GameplayAbilitySystemSet::EffectTicks.before(GameplayAbilitySystemSet::Queues),
                     GameplayAbilitySystemSet::AbilityTasks.before(GameplayAbilitySystemSet::Queues),
                     GameplayAbilitySystemSet::Queues.before(GameplayAbilitySystemSet::Cleanup),
@@ -70,7 +88,17 @@ impl Plugin for GameplayAbilitySystemRuntimePlugin {
             )
             .add_systems(
                 FixedUpdate,
-                (tick_effect_duration_system, tick_effect_period_system)
+                advance_gameplay_time_scales_system.in_set(GameplayAbilitySystemSet::AdvanceTime),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    tick_effect_duration_system,
+                    tick_effect_period_system,
+                    tick_attribute_regeneration_system
+                        .after(tick_effect_duration_system)
+                        .after(tick_effect_period_system),
+                )
                     .in_set(GameplayAbilitySystemSet::EffectTicks),
             )
             .add_systems(
@@ -98,12 +126,39 @@ impl Plugin for GameplayAbilitySystemRuntimePlugin {
             )
             .add_systems(
                 FixedUpdate,
-                recalculate_attribute_sets_system
+                (
+                    recalculate_attribute_sets_system,
+                    update_attribute_thresholds_system.after(recalculate_attribute_sets_system),
+                    record_attribute_history_system.after(recalculate_attribute_sets_system),
+                )
                     .in_set(GameplayAbilitySystemSet::RecalculateAttributes),
             );
     }
 }
 
+/// Loads `.effect.ron` and `.ability.ron` definitions and resolves them into
+/// [`GameplayDefinitions`]. Needs `AssetPlugin`, so it is not part of
+/// [`GameplayAbilitySystemPlugin`].
+pub struct GameplayDefinitionAssetPlugin;
+
+impl Plugin for GameplayDefinitionAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<GameplayEffectAsset>()
+            .init_asset::<GameplayAbilityAsset>()
+            .init_asset_loader::<GameplayEffectAssetLoader>()
+            .init_asset_loader::<GameplayAbilityAssetLoader>()
+            .init_resource::<GameplayDefinitions>()
+            .add_systems(
+                Update,
+                (
+                    resolve_gameplay_definitions_system,
+                    apply_gameplay_definition_reloads_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
 pub struct GameplayAbilitySystemPlugin;
 
 impl PluginGroup for GameplayAbilitySystemPlugin {
@@ -68,6 +68,16 @@ impl UniqueNamePool {
         UniqueName(self.get_or_insert(name))
     }
 
+    /// Looks `name` up without adding it to the pool.
+    pub fn find_name(&self, name: &str) -> Option<UniqueName> {
+        if name.is_empty() {
+            return Some(UniqueName(0));
+        }
+        let index = *self.lookup_hash.get(&compute_hash(name))?;
+        (self.entry_pool.get(index as usize).map(String::as_str) == Some(name))
+            .then_some(UniqueName(index))
+    }
+
     pub fn get_display_str(&self, name: &UniqueName) -> &str {
         self.entry_pool
             .get(name.0 as usize)
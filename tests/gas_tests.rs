@@ -7,9 +7,22 @@ mod active_gameplay_effect_test;
 #[path = "gas_tests/attributes_test.rs"]
 mod attributes_test;
 
+#[path = "gas_tests/curves_test.rs"]
+mod curves_test;
+
+#[path = "gas_tests/definitions_test.rs"]
+mod definitions_test;
+
+#[path = "gas_tests/fixed_point_test.rs"]
+mod fixed_point_test;
+
 #[path = "gas_tests/gameplay_abilities_test.rs"]
 mod gameplay_abilities_test;
 
+#[cfg(feature = "derive")]
+#[path = "gas_tests/gameplay_attributes_test.rs"]
+mod gameplay_attributes_test;
+
 #[path = "gas_tests/gameplay_effects_test.rs"]
 mod gameplay_effects_test;
 
@@ -71,7 +71,7 @@ fn apply_effect_system(
     mut result: ResMut<ApplyResult>,
 ) {
     let payload = EffectPayload::new(target.0, None, 1);
-    result.0 = apply_gameplay_effect(target.0, &effect.0, &mut params, &payload);
+    result.0 = apply_gameplay_effect(target.0, &effect.0, &mut params, &payload).is_ok();
 }
 
 #[test]
@@ -1,11 +1,19 @@
 use super::common_test::{
-    active_effect_handles, apply_effect, attribute_set, current_value, empty_effect_tags,
-    register_attribute, run_effect_duration_tick, test_app,
+    active_effect_handles, add_modifier, add_tag_to_entity, apply_effect,
+    apply_effect_with_payload, attribute_set, current_value, effect_tags, empty_effect_tags,
+    instant_add_effect, modifier, register_attribute, register_tag, remove_tag_from_entity,
+    run_attribute_history_record, run_attribute_regeneration_tick, run_attribute_thresholds_update,
+    run_effect_duration_tick, test_app,
 };
+use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
 use bevy_tools::{
-    AttributeClamp, AttributeIdManager, AttributeSet, EffectDurationTicks, GameplayEffect,
-    ModifierMagnitude, ModifierOperation, StackingPolicy, UniqueNamePool,
+    ActiveGameplayEffect, AttributeChangeCause, AttributeClamp, AttributeHistory,
+    AttributeHistoryClock, AttributeIdManager, AttributeRegeneration, AttributeSet,
+    AttributeThreshold, AttributeThresholdEvent, AttributeThresholdState, AttributeThresholds,
+    EffectDurationTicks, EffectPayload, EffectTags, GameplayEffect, GameplayEffectImmunityQuery,
+    GameplayTagContainer, ModifierMagnitude, ModifierOperation, Regeneration, StackingPolicy,
+    TagRequirements, ThresholdDirection, ThresholdValue, UniqueNamePool, get_attribute_breakdown,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -313,3 +321,518 @@ fn attribute_set_snapshot_is_not_changed_by_later_attribute_mutation() {
     assert_eq!(snapshot.get_base_value(health), Some(10.0));
     assert_eq!(snapshot.get_current_value(health), Some(10.0));
 }
+
+#[test]
+fn partial_snapshot_captures_tags_for_requirements_and_diffs() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let mana = register_attribute(&mut app, "Mana");
+    let damage = register_attribute(&mut app, "Damage");
+    let empowered = register_tag(&mut app, "State.Empowered");
+    let mut source_attributes = attribute_set(health, 100.0, AttributeClamp::None);
+    source_attributes.initialize_attribute(mana, 50.0, None, AttributeClamp::None);
+    let source = app
+        .world_mut()
+        .spawn((source_attributes, GameplayTagContainer::default()))
+        .id();
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(damage, 0.0, AttributeClamp::None))
+        .id();
+    add_tag_to_entity(&mut app, source, empowered);
+    let take_snapshot = |app: &mut App| {
+        let mut entity = app.world_mut().entity_mut(source);
+        let tags = entity.get::<GameplayTagContainer>().unwrap().clone();
+        entity
+            .get_mut::<AttributeSet>()
+            .unwrap()
+            .make_partial_snapshot(source, [health])
+            .with_tags(&tags)
+    };
+
+    let before = take_snapshot(&mut app);
+    assert_eq!(before.get_current_value(health), Some(100.0));
+    assert_eq!(before.get_current_value(mana), None);
+
+    assert!(apply_effect(
+        &mut app,
+        source,
+        source,
+        instant_add_effect(health, -30.0)
+    ));
+    remove_tag_from_entity(&mut app, source, empowered);
+    let after = take_snapshot(&mut app);
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.get_changes().len(), 1);
+    assert_eq!(
+        diff.get_change(health).unwrap().get_current_delta(),
+        Some(-30.0)
+    );
+    assert!(diff.has_removed_tag(&empowered));
+    assert!(!diff.has_added_tag(&empowered));
+    assert!(before.diff(&before).is_empty());
+
+    // Source application requirements read the tags captured with the snapshot.
+    let empowered_strike = Arc::new(GameplayEffect::new(
+        vec![add_modifier(damage, 10.0)],
+        EffectDurationTicks::Instant,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        EffectTags::new(
+            Vec::new(),
+            Vec::new(),
+            TagRequirements::new(vec![empowered], Vec::new()),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            Vec::new(),
+            Vec::new(),
+        ),
+    ));
+    assert!(!apply_effect(
+        &mut app,
+        target,
+        source,
+        empowered_strike.clone()
+    ));
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        empowered_strike,
+        EffectPayload::new(source, None, 1).with_source_snapshot(before.clone()),
+    ));
+    assert_eq!(current_value(&mut app, target, damage), 10.0);
+
+    // Modifier tag requirements read the same snapshot tags.
+    let empowered_bonus = Arc::new(GameplayEffect::new(
+        vec![
+            add_modifier(damage, 5.0)
+                .with_source_tag_requirements(TagRequirements::new(vec![empowered], Vec::new())),
+        ],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        effect_tags(Vec::new(), Vec::new()),
+    ));
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        empowered_bonus,
+        EffectPayload::new(source, None, 1).with_source_snapshot(before.clone()),
+    ));
+    assert_eq!(current_value(&mut app, target, damage), 15.0);
+
+    // Application immunity reads the snapshot tags as well, whatever the live source tags are.
+    let empowered_immunity = Arc::new(GameplayEffect::new(
+        Vec::new(),
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        EffectTags::new(
+            Vec::new(),
+            Vec::new(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            TagRequirements::default(),
+            vec![GameplayEffectImmunityQuery::new(
+                TagRequirements::new(vec![empowered], Vec::new()),
+                TagRequirements::default(),
+            )],
+            Vec::new(),
+        ),
+    ));
+    assert!(apply_effect(&mut app, target, target, empowered_immunity));
+    add_tag_to_entity(&mut app, source, empowered);
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        instant_add_effect(damage, 1.0),
+        EffectPayload::new(source, None, 1).with_source_snapshot(after),
+    ));
+    assert_eq!(current_value(&mut app, target, damage), 16.0);
+    remove_tag_from_entity(&mut app, source, empowered);
+    assert!(!apply_effect_with_payload(
+        &mut app,
+        target,
+        instant_add_effect(damage, 1.0),
+        EffectPayload::new(source, None, 1).with_source_snapshot(before),
+    ));
+    assert_eq!(current_value(&mut app, target, damage), 16.0);
+}
+
+#[test]
+fn attribute_breakdown_lists_contributions_with_source_effect_tags() {
+    let mut app = test_app();
+    let attack = register_attribute(&mut app, "Attack");
+    let weapon_tag = register_tag(&mut app, "Effect.Weapon");
+    let buff_tag = register_tag(&mut app, "Effect.Buff");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(
+            attack,
+            100.0,
+            AttributeClamp::Range {
+                min: None,
+                max: Some(150.0),
+            },
+        ))
+        .id();
+    let weapon = Arc::new(GameplayEffect::new(
+        vec![add_modifier(attack, 30.0)],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        effect_tags(vec![weapon_tag], Vec::new()),
+    ));
+    let buff = Arc::new(GameplayEffect::new(
+        vec![modifier(attack, ModifierOperation::PercentAdd, 0.5)],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        effect_tags(vec![buff_tag], Vec::new()),
+    ));
+    assert!(apply_effect(&mut app, target, target, weapon));
+    assert!(apply_effect(&mut app, target, target, buff));
+    let handles = active_effect_handles(&app, target);
+
+    let breakdown = app
+        .world_mut()
+        .run_system_once(
+            move |mut attr_query: Query<&mut AttributeSet>,
+                  active_effect_query: Query<(Entity, &ActiveGameplayEffect)>| {
+                get_attribute_breakdown(target, attack, &mut attr_query, &active_effect_query)
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(breakdown.get_base_value(), 100.0);
+    assert_eq!(breakdown.get_modifiers().len(), 2);
+    let add = &breakdown.get_modifiers()[0];
+    assert_eq!(add.get_operation(), ModifierOperation::Add);
+    assert_eq!(add.get_value(), 30.0);
+    assert_eq!(add.get_source_effect(), handles[0]);
+    assert_eq!(add.get_source_asset_tags(), &[weapon_tag]);
+    let percent = &breakdown.get_modifiers()[1];
+    assert_eq!(percent.get_operation(), ModifierOperation::PercentAdd);
+    assert_eq!(percent.get_source_asset_tags(), &[buff_tag]);
+    assert_eq!(breakdown.contributions_from(handles[1]).count(), 1);
+    assert!(breakdown.get_override().is_none());
+    assert_eq!(breakdown.get_evaluated_value(), 195.0);
+    assert_eq!(breakdown.get_current_value(), 150.0);
+    assert_eq!(
+        breakdown.get_clamp(),
+        AttributeClamp::Range {
+            min: None,
+            max: Some(150.0),
+        }
+    );
+}
+
+#[test]
+fn regeneration_respects_cap_damage_delay_and_suppression_tags() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let max_health = register_attribute(&mut app, "MaxHealth");
+    let health_regen = register_attribute(&mut app, "HealthRegen");
+    let no_regen = register_tag(&mut app, "State.NoRegen");
+    let mut attributes = attribute_set(health, 90.0, AttributeClamp::None);
+    attributes.initialize_attribute(max_health, 100.0, None, AttributeClamp::None);
+    attributes.initialize_attribute(health_regen, 4.0, None, AttributeClamp::None);
+    let target = app
+        .world_mut()
+        .spawn((
+            attributes,
+            GameplayTagContainer::default(),
+            AttributeRegeneration::new(vec![
+                Regeneration::new(health, health_regen)
+                    .with_cap(max_health)
+                    .with_delay_after_decrease(2)
+                    .with_tag_requirements(TagRequirements::new(Vec::new(), vec![no_regen])),
+            ]),
+        ))
+        .id();
+    run_attribute_regeneration_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 94.0);
+
+    assert!(apply_effect(
+        &mut app,
+        target,
+        target,
+        instant_add_effect(health, -20.0)
+    ));
+    run_attribute_regeneration_tick(&mut app);
+    run_attribute_regeneration_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 74.0);
+    run_attribute_regeneration_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 78.0);
+
+    add_tag_to_entity(&mut app, target, no_regen);
+    run_attribute_regeneration_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 78.0);
+    remove_tag_from_entity(&mut app, target, no_regen);
+
+    for _ in 0..10 {
+        run_attribute_regeneration_tick(&mut app);
+    }
+    assert_eq!(current_value(&mut app, target, health), 100.0);
+}
+
+#[derive(Resource, Default)]
+struct ThresholdEvents(Vec<(usize, AttributeThresholdState)>);
+
+#[test]
+fn attribute_thresholds_grant_tags_with_hysteresis_and_fire_events() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let max_health = register_attribute(&mut app, "MaxHealth");
+    let low_health = register_tag(&mut app, "State.LowHealth");
+    let dead = register_tag(&mut app, "State.Dead");
+    app.init_resource::<ThresholdEvents>().add_observer(
+        |event: On<AttributeThresholdEvent>, mut events: ResMut<ThresholdEvents>| {
+            events
+                .0
+                .push((event.get_threshold_index(), event.get_state()));
+        },
+    );
+    let mut attributes = attribute_set(health, 100.0, AttributeClamp::None);
+    attributes.initialize_attribute(max_health, 100.0, None, AttributeClamp::None);
+    let target = app
+        .world_mut()
+        .spawn((
+            attributes,
+            GameplayTagContainer::default(),
+            AttributeThresholds::new(vec![
+                AttributeThreshold::new(
+                    health,
+                    ThresholdValue::RatioOf {
+                        attribute: max_health,
+                        ratio: 0.3,
+                    },
+                    ThresholdDirection::AtOrBelow,
+                )
+                .with_hysteresis(5.0)
+                .with_granted_tags(vec![low_health]),
+                AttributeThreshold::new(
+                    health,
+                    ThresholdValue::Absolute(0.0),
+                    ThresholdDirection::AtOrBelow,
+                )
+                .with_granted_tags(vec![dead]),
+            ]),
+        ))
+        .id();
+    let has_tag = |app: &App, tag| {
+        app.world()
+            .entity(target)
+            .get::<GameplayTagContainer>()
+            .unwrap()
+            .has_tag(&tag)
+    };
+    let change_health = |app: &mut App, value: f64| {
+        assert!(apply_effect(
+            app,
+            target,
+            target,
+            instant_add_effect(health, value)
+        ));
+        run_attribute_thresholds_update(app);
+    };
+
+    run_attribute_thresholds_update(&mut app);
+    assert!(app.world().resource::<ThresholdEvents>().0.is_empty());
+
+    change_health(&mut app, -75.0);
+    assert!(has_tag(&app, low_health));
+    change_health(&mut app, 8.0);
+    assert!(has_tag(&app, low_health));
+    change_health(&mut app, 5.0);
+    assert!(!has_tag(&app, low_health));
+
+    change_health(&mut app, -38.0);
+    assert!(has_tag(&app, low_health));
+    assert!(has_tag(&app, dead));
+    assert_eq!(
+        app.world().resource::<ThresholdEvents>().0,
+        vec![
+            (0, AttributeThresholdState::Entered),
+            (0, AttributeThresholdState::Exited),
+            (0, AttributeThresholdState::Entered),
+            (1, AttributeThresholdState::Entered),
+        ]
+    );
+
+    app.world_mut()
+        .entity_mut(target)
+        .remove::<AttributeThresholds>();
+    assert!(!has_tag(&app, low_health));
+    assert!(!has_tag(&app, dead));
+}
+
+#[test]
+fn attribute_history_records_ring_buffer_with_change_causes() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let target = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 100.0, AttributeClamp::None),
+            AttributeHistory::new([health], 3),
+        ))
+        .id();
+    let source = app.world_mut().spawn_empty().id();
+    let regen_buff = Arc::new(GameplayEffect::new(
+        vec![add_modifier(health, 5.0)],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    run_attribute_history_record(&mut app);
+    assert!(apply_effect(
+        &mut app,
+        target,
+        source,
+        instant_add_effect(health, -10.0)
+    ));
+    run_attribute_history_record(&mut app);
+    assert!(apply_effect(&mut app, target, source, regen_buff));
+    let buff_handle = active_effect_handles(&app, target)[0];
+    run_attribute_history_record(&mut app);
+    run_attribute_history_record(&mut app);
+
+    assert_eq!(
+        app.world().resource::<AttributeHistoryClock>().get_tick(),
+        4
+    );
+    let history = app
+        .world()
+        .entity(target)
+        .get::<AttributeHistory>()
+        .unwrap();
+    let samples: Vec<_> = history
+        .get_samples(health)
+        .unwrap()
+        .map(|sample| {
+            (
+                sample.get_tick(),
+                sample.get_base_value(),
+                sample.get_current_value(),
+                sample.get_cause(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        samples,
+        vec![
+            (
+                1,
+                90.0,
+                90.0,
+                Some(AttributeChangeCause::InstantEffect { source })
+            ),
+            (
+                2,
+                90.0,
+                95.0,
+                Some(AttributeChangeCause::ActiveEffect(buff_handle))
+            ),
+            (3, 90.0, 95.0, None),
+        ]
+    );
+
+    let csv = history.to_csv_with_labels(|_| "Health".to_string());
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines[0], "tick,attribute,base,current,cause");
+    assert_eq!(lines[1], format!("1,Health,90,90,instant_effect:{source}"));
+    assert_eq!(lines[3], "3,Health,90,95,");
+}
+
+#[test]
+fn attribute_history_uses_global_ticks_and_resets_causes() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let first = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 100.0, AttributeClamp::None),
+            AttributeHistory::new([health], 4),
+        ))
+        .id();
+    let source = app.world_mut().spawn_empty().id();
+    run_attribute_history_record(&mut app);
+    run_attribute_history_record(&mut app);
+
+    let late = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 100.0, AttributeClamp::None),
+            AttributeHistory::new([health], 4),
+        ))
+        .id();
+    assert!(apply_effect(
+        &mut app,
+        first,
+        source,
+        instant_add_effect(health, -10.0)
+    ));
+    run_attribute_history_record(&mut app);
+    // A clamp change has no cause of its own, so it must not be credited to the instant effect.
+    app.world_mut()
+        .get_mut::<AttributeSet>(first)
+        .unwrap()
+        .set_attribute_clamp(
+            health,
+            AttributeClamp::Range {
+                min: None,
+                max: Some(50.0),
+            },
+        );
+    run_attribute_history_record(&mut app);
+
+    let samples = |app: &App, entity: Entity| -> Vec<_> {
+        app.world()
+            .get::<AttributeHistory>(entity)
+            .unwrap()
+            .get_samples(health)
+            .unwrap()
+            .map(|sample| {
+                (
+                    sample.get_tick(),
+                    sample.get_current_value(),
+                    sample.get_cause(),
+                )
+            })
+            .collect()
+    };
+    assert_eq!(
+        samples(&app, first),
+        vec![
+            (0, 100.0, None),
+            (1, 100.0, None),
+            (
+                2,
+                90.0,
+                Some(AttributeChangeCause::InstantEffect { source })
+            ),
+            (3, 50.0, None),
+        ]
+    );
+    assert_eq!(
+        samples(&app, late),
+        vec![(2, 100.0, None), (3, 100.0, None)]
+    );
+}
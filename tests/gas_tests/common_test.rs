@@ -3,8 +3,8 @@ use bevy::prelude::*;
 use bevy_tools::attributes::{AttributeClamp, AttributeId, AttributeIdRegister, AttributeSet};
 use bevy_tools::gameplay_abilities::{AbilitySpecHandle, AbilityTask, ActiveGameplayAbility};
 use bevy_tools::gameplay_effects::{
-    EffectDurationTicks, EffectPayload, EffectTags, GameplayEffect, GameplayEffectApplicationQueue,
-    TagRequirements,
+    EffectApplicationError, EffectDurationTicks, EffectPayload, EffectTags, GameplayEffect,
+    GameplayEffectApplicationQueue, TagRequirements,
 };
 use bevy_tools::gameplay_tags::{
     GameplayTag, GameplayTagContainer, GameplayTagManager, GameplayTagRegister,
@@ -15,9 +15,10 @@ use bevy_tools::{
     AbilitySystemParams, ActiveGameplayEffectTargetIndex, GameplayAbilitySystemPlugin,
     apply_gameplay_effect, cleanup_finished_abilities_system,
     process_ability_activation_queue_system, process_gameplay_effect_application_queue_system,
-    reconcile_active_effect_target_index_system, tick_ability_tasks_system,
-    tick_effect_duration_system, tick_effect_period_system, try_activate_ability_by_handle,
-    update_active_effect_tag_requirements_system,
+    reconcile_active_effect_target_index_system, record_attribute_history_system,
+    tick_ability_tasks_system, tick_attribute_regeneration_system, tick_effect_duration_system,
+    tick_effect_period_system, try_activate_ability_by_handle,
+    update_active_effect_tag_requirements_system, update_attribute_thresholds_system,
 };
 use std::sync::Arc;
 
@@ -133,6 +134,15 @@ pub fn apply_effect_with_payload(
     effect: Arc<GameplayEffect>,
     payload: EffectPayload,
 ) -> bool {
+    apply_effect_result(app, target, effect, payload).is_ok()
+}
+
+pub fn apply_effect_result(
+    app: &mut App,
+    target: Entity,
+    effect: Arc<GameplayEffect>,
+    payload: EffectPayload,
+) -> Result<(), EffectApplicationError> {
     app.world_mut()
         .run_system_once(move |mut params: AbilitySystemParams| {
             apply_gameplay_effect(target, &effect, &mut params, &payload)
@@ -222,6 +232,24 @@ pub fn run_effect_period_tick(app: &mut App) {
         .unwrap();
 }
 
+pub fn run_attribute_regeneration_tick(app: &mut App) {
+    app.world_mut()
+        .run_system_once(tick_attribute_regeneration_system)
+        .unwrap();
+}
+
+pub fn run_attribute_history_record(app: &mut App) {
+    app.world_mut()
+        .run_system_once(record_attribute_history_system)
+        .unwrap();
+}
+
+pub fn run_attribute_thresholds_update(app: &mut App) {
+    app.world_mut()
+        .run_system_once(update_attribute_thresholds_system)
+        .unwrap();
+}
+
 pub fn run_effect_tag_requirements_update(app: &mut App) {
     app.world_mut()
         .run_system_once(update_active_effect_tag_requirements_system)
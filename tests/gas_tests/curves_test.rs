@@ -0,0 +1,149 @@
+use super::common_test::{
+    active_effect_handles, apply_effect_with_payload, attribute_set, current_value,
+    empty_effect_tags, register_attribute, run_effect_duration_tick, test_app,
+};
+use bevy_tools::{
+    AttributeClamp, CurveInterpolation, CurveKey, CurveTable, CurveTableError, EffectDurationTicks,
+    EffectPayload, GameplayEffect, Modifier, ModifierMagnitude, ModifierOperation, RealCurve,
+    ScalableFloat, StackingPolicy,
+};
+use std::sync::Arc;
+
+fn curve(interpolation: CurveInterpolation) -> RealCurve {
+    RealCurve::new(
+        vec![
+            CurveKey::new(3.0, 30.0),
+            CurveKey::new(1.0, 10.0),
+            CurveKey::new(5.0, 10.0),
+        ],
+        interpolation,
+    )
+}
+
+#[test]
+fn real_curve_interpolates_between_sorted_keys_and_clamps_outside_range() {
+    let linear = curve(CurveInterpolation::Linear);
+    assert_eq!(linear.evaluate(0.0), 10.0);
+    assert_eq!(linear.evaluate(2.0), 20.0);
+    assert_eq!(linear.evaluate(4.0), 20.0);
+    assert_eq!(linear.evaluate(9.0), 10.0);
+
+    let constant = curve(CurveInterpolation::Constant);
+    assert_eq!(constant.evaluate(2.9), 10.0);
+    assert_eq!(constant.evaluate(3.0), 30.0);
+
+    let cubic = curve(CurveInterpolation::Cubic);
+    assert_eq!(cubic.evaluate(1.0), 10.0);
+    assert_eq!(cubic.evaluate(3.0), 30.0);
+    assert!(cubic.evaluate(2.0) > 10.0 && cubic.evaluate(2.0) < 30.0);
+    assert_eq!(cubic.evaluate(2.0), cubic.evaluate(4.0));
+
+    assert_eq!(
+        RealCurve::new(Vec::new(), CurveInterpolation::Linear).evaluate(1.0),
+        0.0
+    );
+}
+
+#[test]
+fn curve_table_loads_csv_rows_and_skips_empty_cells() {
+    let table = CurveTable::from_csv(
+        "Name,1,2,3\nDamage,10,20,40\nDuration,5,,15\n",
+        CurveInterpolation::Linear,
+    )
+    .unwrap();
+
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.evaluate("Damage", 2.5), Some(30.0));
+    assert_eq!(table.get_curve("Duration").unwrap().get_keys().len(), 2);
+    assert_eq!(table.evaluate("Duration", 2.0), Some(10.0));
+    assert_eq!(table.evaluate("Missing", 1.0), None);
+
+    assert_eq!(
+        CurveTable::from_csv("Name,1\nDamage,ten\n", CurveInterpolation::Linear).unwrap_err(),
+        CurveTableError::InvalidCsvValue {
+            row: "Damage".to_string(),
+            column: 1,
+            value: "ten".to_string(),
+        }
+    );
+    assert_eq!(
+        CurveTable::from_csv("Name,1\nDamage,1\nDamage,2\n", CurveInterpolation::Linear)
+            .unwrap_err(),
+        CurveTableError::DuplicateCurve {
+            name: "Damage".to_string(),
+        }
+    );
+}
+
+#[test]
+fn curve_table_loads_ron_with_per_curve_interpolation() {
+    let table = CurveTable::from_ron(
+        r#"{
+            "Damage": (keys: [(time: 1.0, value: 10.0), (time: 3.0, value: 30.0)]),
+            "Period": (
+                interpolation: Constant,
+                keys: [(time: 1.0, value: 4.0), (time: 3.0, value: 2.0)],
+            ),
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        table.get_curve("Damage").unwrap().get_interpolation(),
+        CurveInterpolation::Linear
+    );
+    assert_eq!(table.evaluate("Damage", 2.0), Some(20.0));
+    assert_eq!(table.evaluate("Period", 2.0), Some(4.0));
+    assert!(matches!(
+        CurveTable::from_ron("{ \"Damage\": (keys: 1) }"),
+        Err(CurveTableError::InvalidRon { .. })
+    ));
+}
+
+#[test]
+fn scalable_float_scales_modifier_and_duration_by_effect_level() {
+    let mut app = test_app();
+    let armor = register_attribute(&mut app, "Armor");
+    let table = CurveTable::from_csv(
+        "Name,1,2,3\nArmor,1,2,3\nDuration,1,2,3\n",
+        CurveInterpolation::Linear,
+    )
+    .unwrap();
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(armor, 0.0, AttributeClamp::None))
+        .id();
+    let effect = Arc::new(GameplayEffect::new(
+        vec![Modifier::new(
+            armor,
+            ModifierOperation::Add,
+            ModifierMagnitude::ScalableFloat(ScalableFloat::new(
+                5.0,
+                table.get_curve("Armor").unwrap().clone(),
+            )),
+        )],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::ScalableFloat(ScalableFloat::new(
+            1.0,
+            table.get_curve("Duration").unwrap().clone(),
+        ))),
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        effect,
+        EffectPayload::new(target, None, 3),
+    ));
+    assert_eq!(current_value(&mut app, target, armor), 15.0);
+
+    run_effect_duration_tick(&mut app);
+    run_effect_duration_tick(&mut app);
+    assert_eq!(active_effect_handles(&app, target).len(), 1);
+    run_effect_duration_tick(&mut app);
+    assert!(active_effect_handles(&app, target).is_empty());
+    assert_eq!(current_value(&mut app, target, armor), 0.0);
+}
@@ -0,0 +1,369 @@
+use super::common_test::{
+    active_effect_handles, apply_effect, attribute_set, current_value, give_ability,
+    register_attribute, register_tag, test_app,
+};
+use bevy::asset::UntypedAssetId;
+use bevy::asset::io::memory::{Dir, MemoryAssetReader};
+use bevy::asset::io::{AssetSourceBuilder, AssetSourceId};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_tools::{
+    AbilitySystemComponent, AbilityTaskDef, AbilityTaskOnFinishedDef, ActiveEffectDurationTicks,
+    ActiveGameplayEffect, AttributeClamp, AttributeIdManager, DurationDefinition,
+    EffectPeriodTicks, EffectPeriodUnit, GameplayAbility, GameplayAbilityAsset,
+    GameplayAbilityDefinition, GameplayDefinitionAssetPlugin, GameplayDefinitionError,
+    GameplayDefinitionReloadPolicy, GameplayDefinitionResolveFailedEvent,
+    GameplayDefinitionResolver, GameplayDefinitions, GameplayEffect, GameplayEffectAsset,
+    GameplayEffectDefinition, GameplayTagContainer, GameplayTagManager, MagnitudeDefinition,
+    UniqueNamePool,
+};
+use std::path::Path;
+use std::sync::Arc;
+
+fn resolve_effect(app: &mut App, ron: &str) -> Result<GameplayEffect, GameplayDefinitionError> {
+    let definition = GameplayEffectDefinition::from_ron(ron)?;
+    app.world_mut()
+        .run_system_once(
+            move |mut names: ResMut<UniqueNamePool>,
+                  tag_manager: Res<GameplayTagManager>,
+                  attribute_ids: Res<AttributeIdManager>| {
+                definition.resolve(&mut GameplayDefinitionResolver::new(
+                    &mut names,
+                    &tag_manager,
+                    &attribute_ids,
+                ))
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn effect_definition_resolves_names_and_applies() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let burning = register_tag(&mut app, "State.Burning");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(health, 100.0, AttributeClamp::None))
+        .id();
+
+    let effect = resolve_effect(
+        &mut app,
+        r#"(
+            modifiers: [(attribute: "Health", operation: Add, magnitude: Flat(-15.0))],
+            tags: (asset_tags: ["State.Burning"]),
+        )"#,
+    )
+    .unwrap();
+
+    assert_eq!(effect.get_modifiers().len(), 1);
+    assert_eq!(effect.get_tags().get_asset_tags(), &[burning]);
+    assert!(apply_effect(&mut app, target, target, Arc::new(effect)));
+    assert_eq!(current_value(&mut app, target, health), 85.0);
+}
+
+#[test]
+fn effect_definition_reads_period_unit() {
+    let mut app = test_app();
+
+    let in_ticks = resolve_effect(
+        &mut app,
+        r#"(duration: Infinite, period: Some((period: Flat(2.0))))"#,
+    )
+    .unwrap();
+    let in_seconds = resolve_effect(
+        &mut app,
+        r#"(duration: Infinite, period: Some((period: Flat(0.5), unit: Seconds)))"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        in_ticks.get_period().map(EffectPeriodTicks::get_unit),
+        Some(EffectPeriodUnit::Ticks)
+    );
+    assert_eq!(
+        in_seconds.get_period().map(EffectPeriodTicks::get_unit),
+        Some(EffectPeriodUnit::Seconds)
+    );
+}
+
+#[test]
+fn effect_definition_reports_unknown_names() {
+    let mut app = test_app();
+    register_attribute(&mut app, "Health");
+
+    let unknown_tag = resolve_effect(&mut app, r#"(tags: (granted_tags: ["State.Frozen"]))"#);
+    assert!(matches!(
+        unknown_tag,
+        Err(GameplayDefinitionError::UnknownTag { name }) if name == "State.Frozen"
+    ));
+
+    let unknown_attribute = resolve_effect(
+        &mut app,
+        r#"(modifiers: [(attribute: "Mana", operation: Add, magnitude: Flat(1.0))])"#,
+    );
+    assert!(matches!(
+        unknown_attribute,
+        Err(GameplayDefinitionError::UnknownAttribute { name }) if name == "Mana"
+    ));
+
+    assert!(matches!(
+        GameplayAbilityDefinition::from_ron("(startup_tasks: [Sleep])"),
+        Err(GameplayDefinitionError::InvalidRon { .. })
+    ));
+}
+
+/// App with the example fireball ability and burn effect from `assets/gas` resolved.
+fn fireball_app() -> (
+    App,
+    Handle<GameplayAbilityAsset>,
+    Handle<GameplayEffectAsset>,
+) {
+    let mut app = test_app();
+    app.add_plugins((AssetPlugin::default(), GameplayDefinitionAssetPlugin));
+    for attribute in ["Health", "Mana", "Power"] {
+        register_attribute(&mut app, attribute);
+    }
+    for tag in [
+        "Effect.Burn",
+        "State.Burning",
+        "State.FireImmune",
+        "State.Silenced",
+        "Ability.Fireball",
+    ] {
+        register_tag(&mut app, tag);
+    }
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let ability: Handle<GameplayAbilityAsset> = asset_server.load("gas/fireball.ability.ron");
+    let burn: Handle<GameplayEffectAsset> = asset_server.load("gas/burn.effect.ron");
+
+    let ability_id = ability.id();
+    run_app_until(&mut app, |world| {
+        let definitions = world.resource::<GameplayDefinitions>();
+        if let Some(err) = definitions.get_error(ability_id) {
+            panic!("fireball ability failed to resolve: {err}");
+        }
+        definitions.get_ability(ability_id).is_some()
+    });
+    (app, ability, burn)
+}
+
+/// Updates `app` until `done` holds, like bevy's own asset tests: loads finish on the task
+/// pools, so the wait is bounded by a number of updates rather than by wall-clock time.
+fn run_app_until(app: &mut App, mut done: impl FnMut(&World) -> bool) {
+    const MAX_UPDATES: usize = 10_000;
+    for _ in 0..MAX_UPDATES {
+        app.update();
+        if done(app.world()) {
+            return;
+        }
+    }
+    panic!("condition not reached within {MAX_UPDATES} updates");
+}
+
+/// App that loads definition assets from `dir` in memory instead of the `assets` folder.
+fn memory_asset_app(dir: Dir) -> App {
+    let mut app = test_app();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSourceBuilder::new(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+    )
+    .add_plugins((AssetPlugin::default(), GameplayDefinitionAssetPlugin));
+    app
+}
+
+fn task_effect(ability: &GameplayAbility) -> &Arc<GameplayEffect> {
+    let AbilityTaskDef::WaitTicks {
+        on_finished: AbilityTaskOnFinishedDef::ApplyGameplayEffectToTarget { effect },
+        ..
+    } = &ability.get_startup_tasks()[0]
+    else {
+        panic!("expected the first task to apply the burn effect");
+    };
+    effect
+}
+
+#[test]
+fn ability_asset_shares_referenced_effect_asset() {
+    let (app, ability, burn) = fireball_app();
+
+    let definitions = app.world().resource::<GameplayDefinitions>();
+    let fireball = definitions.get_ability(&ability).unwrap();
+    let burn = definitions.get_effect(&burn).unwrap();
+    assert!(Arc::ptr_eq(task_effect(fireball), burn));
+    assert_eq!(burn.get_tags().get_granted_tags().len(), 1);
+    assert!(fireball.get_cost().is_some());
+}
+
+#[test]
+fn reloaded_effect_asset_rebuilds_active_effects_and_granted_abilities() {
+    let (mut app, ability, burn) = fireball_app();
+    app.world_mut()
+        .resource_mut::<GameplayDefinitions>()
+        .set_reload_policy(GameplayDefinitionReloadPolicy::RebuildActiveSpecs);
+    let health = register_attribute(&mut app, "Health");
+    let target = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 100.0, AttributeClamp::None),
+            GameplayTagContainer::default(),
+            AbilitySystemComponent::default(),
+        ))
+        .id();
+    let definitions = app.world().resource::<GameplayDefinitions>();
+    let old_fireball = definitions.get_ability(&ability).unwrap().clone();
+    let old_burn = definitions.get_effect(&burn).unwrap().clone();
+    let spec_handle = give_ability(&mut app, target, old_fireball.clone());
+    assert!(apply_effect(&mut app, target, target, old_burn.clone()));
+    let effect_handle = active_effect_handles(&app, target)[0];
+
+    app.world_mut()
+        .resource_mut::<Assets<GameplayEffectAsset>>()
+        .get_mut(&burn)
+        .unwrap()
+        .get_definition_mut()
+        .duration = DurationDefinition::Ticks(MagnitudeDefinition::Flat(3.0));
+    // Asset events are flushed after `Update`, so the edit is resolved on the next frame.
+    app.update();
+    app.update();
+
+    let definitions = app.world().resource::<GameplayDefinitions>();
+    let new_burn = definitions.get_effect(&burn).unwrap().clone();
+    let new_fireball = definitions.get_ability(&ability).unwrap().clone();
+    assert!(!Arc::ptr_eq(&old_burn, &new_burn));
+    assert!(Arc::ptr_eq(task_effect(&new_fireball), &new_burn));
+
+    let world = app.world();
+    let active_effect = world.get::<ActiveGameplayEffect>(effect_handle).unwrap();
+    assert!(Arc::ptr_eq(active_effect.get_spec().get_def(), &new_burn));
+    assert_eq!(
+        world
+            .get::<ActiveEffectDurationTicks>(effect_handle)
+            .unwrap()
+            .get_remain_ticks(),
+        3
+    );
+    let ability_system = world.get::<AbilitySystemComponent>(target).unwrap();
+    assert!(Arc::ptr_eq(
+        ability_system
+            .find_ability_spec(spec_handle)
+            .unwrap()
+            .get_ability(),
+        &new_fireball
+    ));
+}
+
+#[derive(Resource, Default)]
+struct ResolveFailures(Vec<(UntypedAssetId, GameplayDefinitionError)>);
+
+#[test]
+fn unresolvable_definitions_report_failures_and_dependency_cycles() {
+    let dir = Dir::default();
+    dir.insert_asset_text(
+        Path::new("a.effect.ron"),
+        r#"(overflow_effects: [Path("b.effect.ron")])"#,
+    );
+    dir.insert_asset_text(
+        Path::new("b.effect.ron"),
+        r#"(overflow_effects: [Path("a.effect.ron")])"#,
+    );
+    dir.insert_asset_text(
+        Path::new("uses_a.effect.ron"),
+        r#"(overflow_effects: [Path("a.effect.ron")])"#,
+    );
+    dir.insert_asset_text(
+        Path::new("frozen.effect.ron"),
+        r#"(tags: (granted_tags: ["State.Frozen"]))"#,
+    );
+    let mut app = memory_asset_app(dir);
+    app.init_resource::<ResolveFailures>().add_observer(
+        |event: On<GameplayDefinitionResolveFailedEvent>, mut failures: ResMut<ResolveFailures>| {
+            failures.0.push((event.get_id(), event.get_error().clone()));
+        },
+    );
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let a: Handle<GameplayEffectAsset> = asset_server.load("a.effect.ron");
+    let b: Handle<GameplayEffectAsset> = asset_server.load("b.effect.ron");
+    let uses_a: Handle<GameplayEffectAsset> = asset_server.load("uses_a.effect.ron");
+    let frozen: Handle<GameplayEffectAsset> = asset_server.load("frozen.effect.ron");
+    run_app_until(&mut app, |world| {
+        world.resource::<ResolveFailures>().0.len() == 4
+    });
+
+    let definitions = app.world().resource::<GameplayDefinitions>();
+    for (handle, path) in [
+        (&a, "b.effect.ron"),
+        (&b, "a.effect.ron"),
+        (&uses_a, "a.effect.ron"),
+    ] {
+        assert_eq!(
+            definitions.get_error(handle),
+            Some(&GameplayDefinitionError::DependencyFailed {
+                path: path.to_string()
+            })
+        );
+        assert!(!definitions.is_pending(handle));
+    }
+    assert_eq!(
+        definitions.get_error(&frozen),
+        Some(&GameplayDefinitionError::UnknownTag {
+            name: "State.Frozen".to_string()
+        })
+    );
+    let failures = &app.world().resource::<ResolveFailures>().0;
+    assert!(failures.iter().any(|(id, err)| *id == frozen.id().untyped()
+        && matches!(err, GameplayDefinitionError::UnknownTag { .. })));
+}
+
+#[test]
+fn reloaded_effect_asset_stacks_with_effects_kept_from_before_the_reload() {
+    let (mut app, _, burn) = fireball_app();
+    let health = register_attribute(&mut app, "Health");
+    let target = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 100.0, AttributeClamp::None),
+            GameplayTagContainer::default(),
+        ))
+        .id();
+    let source = app.world_mut().spawn_empty().id();
+    let old_burn = app
+        .world()
+        .resource::<GameplayDefinitions>()
+        .get_effect(&burn)
+        .unwrap()
+        .clone();
+    assert!(apply_effect(&mut app, target, source, old_burn.clone()));
+
+    app.world_mut()
+        .resource_mut::<Assets<GameplayEffectAsset>>()
+        .get_mut(&burn)
+        .unwrap()
+        .get_definition_mut()
+        .duration = DurationDefinition::Ticks(MagnitudeDefinition::Flat(3.0));
+    app.update();
+    app.update();
+
+    let new_burn = app
+        .world()
+        .resource::<GameplayDefinitions>()
+        .get_effect(&burn)
+        .unwrap()
+        .clone();
+    assert!(!Arc::ptr_eq(&old_burn, &new_burn));
+    assert!(old_burn.get_def_key().is_some());
+    assert_eq!(old_burn.get_def_key(), new_burn.get_def_key());
+
+    assert!(apply_effect(&mut app, target, source, new_burn));
+    let handles = active_effect_handles(&app, target);
+    assert_eq!(handles.len(), 1);
+    assert_eq!(
+        app.world()
+            .get::<ActiveGameplayEffect>(handles[0])
+            .unwrap()
+            .get_stack_count(),
+        2
+    );
+}
@@ -0,0 +1,161 @@
+use super::common_test::{
+    apply_effect, attribute_set, empty_effect_tags, instant_add_effect, modifier,
+    register_attribute, run_effect_duration_tick, run_effect_period_tick, test_app,
+};
+use bevy::prelude::*;
+use bevy_tools::{
+    AttributeClamp, AttributeSet, EffectDurationTicks, EffectPeriodTicks, FixedPoint,
+    GameplayEffect, ModifierMagnitude, ModifierOperation, StackingPolicy,
+};
+use std::sync::Arc;
+
+#[test]
+fn fixed_point_rounds_half_away_from_zero_and_saturates() {
+    let half_ulp = 0.5 / FixedPoint::ONE.to_bits() as f64;
+    assert_eq!(FixedPoint::from_f64(half_ulp).to_bits(), 1);
+    assert_eq!(FixedPoint::from_f64(-half_ulp).to_bits(), -1);
+    assert_eq!(FixedPoint::from_f64(1.5).to_f64(), 1.5);
+    assert_eq!(FixedPoint::from_f64(f64::NAN), FixedPoint::ZERO);
+    assert_eq!(FixedPoint::from_f64(f64::INFINITY).to_bits(), i64::MAX);
+
+    let a = FixedPoint::from_f64(2.5);
+    let b = FixedPoint::from_f64(-1.25);
+    assert_eq!((a * b).to_f64(), -3.125);
+    assert_eq!((a + b).to_f64(), 1.25);
+    assert_eq!((a - b).to_f64(), 3.75);
+    assert_eq!(
+        FixedPoint::from_bits(i64::MAX) + FixedPoint::ONE,
+        FixedPoint::from_bits(i64::MAX)
+    );
+}
+
+/// Runs a fixed mix of instant, duration and periodic effects and returns the target state hash.
+fn run_effect_sequence(ticks: usize) -> u64 {
+    run_effect_sequence_state(ticks).0
+}
+
+/// Target state hash and the fixed-point bits of the current health and armor.
+fn run_effect_sequence_state(ticks: usize) -> (u64, i64, i64) {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let armor = register_attribute(&mut app, "Armor");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(
+            health,
+            100.0,
+            AttributeClamp::Range {
+                min: Some(0.0),
+                max: Some(250.0),
+            },
+        ))
+        .id();
+    app.world_mut()
+        .entity_mut(target)
+        .get_mut::<AttributeSet>()
+        .unwrap()
+        .initialize_attribute(armor, 7.3, None, AttributeClamp::None);
+
+    let buff = Arc::new(GameplayEffect::new(
+        vec![
+            modifier(health, ModifierOperation::PercentAdd, 0.1),
+            modifier(health, ModifierOperation::PercentAdd, 0.2),
+            modifier(armor, ModifierOperation::Multiply, 1.1),
+        ],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(3.0)),
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+    let poison = Arc::new(GameplayEffect::new(
+        vec![modifier(health, ModifierOperation::Add, -3.7)],
+        EffectDurationTicks::Infinite,
+        Some(EffectPeriodTicks::new(ModifierMagnitude::Flat(1.0), true)),
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect(&mut app, target, target, buff));
+    assert!(apply_effect(&mut app, target, target, poison));
+    assert!(apply_effect(
+        &mut app,
+        target,
+        target,
+        instant_add_effect(armor, 0.3)
+    ));
+    for _ in 0..ticks {
+        run_effect_period_tick(&mut app);
+        run_effect_duration_tick(&mut app);
+    }
+
+    let hash = state_hash(&mut app, target);
+    let mut attributes = app.world_mut().get_mut::<AttributeSet>(target).unwrap();
+    let current_bits = |attributes: &mut AttributeSet, id| {
+        FixedPoint::from_f64(attributes.get_current_value(id).unwrap()).to_bits()
+    };
+    (
+        hash,
+        current_bits(&mut attributes, health),
+        current_bits(&mut attributes, armor),
+    )
+}
+
+fn state_hash(app: &mut App, target: Entity) -> u64 {
+    app.world_mut()
+        .entity_mut(target)
+        .get_mut::<AttributeSet>()
+        .unwrap()
+        .make_state_hash()
+}
+
+/// Repeatability within one process only; cross-build determinism is pinned by the golden test.
+#[test]
+fn identical_effect_sequences_produce_identical_state_hashes() {
+    let first = run_effect_sequence(5);
+    let second = run_effect_sequence(5);
+    assert_eq!(first, second);
+
+    // One more poison tick must show up in the hash.
+    assert_ne!(first, run_effect_sequence(6));
+}
+
+/// Golden values pin the fixed-point results across processes, platforms and builds; a change
+/// here breaks lockstep compatibility with peers on an older build.
+#[cfg(feature = "fixed-point")]
+#[test]
+fn fixed_point_effect_sequence_matches_golden_state() {
+    let (hash, health_bits, armor_bits) = run_effect_sequence_state(5);
+    // About 77.8 and 7.6 in Q43.20, including the rounding of every step.
+    assert_eq!(health_bits, 81_579_214);
+    assert_eq!(armor_bits, 7_969_178);
+    assert_eq!(hash, 0x558b_d432_6ef3_25c4);
+}
+
+#[cfg(feature = "fixed-point")]
+#[test]
+fn fixed_point_additive_aggregation_is_order_independent() {
+    let run = |values: [f64; 3]| {
+        let mut app = test_app();
+        let power = register_attribute(&mut app, "Power");
+        let target = app
+            .world_mut()
+            .spawn(attribute_set(power, 0.1, AttributeClamp::None))
+            .id();
+        for value in values {
+            let effect = Arc::new(GameplayEffect::new(
+                vec![modifier(power, ModifierOperation::Add, value)],
+                EffectDurationTicks::Infinite,
+                None,
+                1.0,
+                StackingPolicy::non_stacking(),
+                empty_effect_tags(),
+            ));
+            assert!(apply_effect(&mut app, target, target, effect));
+        }
+        state_hash(&mut app, target)
+    };
+
+    assert_eq!(run([0.2, 0.3, 1e-7]), run([1e-7, 0.3, 0.2]));
+}
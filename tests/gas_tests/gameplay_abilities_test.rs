@@ -5,14 +5,17 @@ use super::common_test::{
     register_tag, run_ability_activation_queue, run_ability_tasks, run_finished_ability_cleanup,
     spawn_ability_task, spawn_active_ability, test_app,
 };
+use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
 use bevy_tools::{
     AbilityActivationContext, AbilityActivationError, AbilityActivationReason,
     AbilityActivationStatus, AbilityChainContext, AbilityChainError, AbilitySpecHandle,
-    AbilitySystemComponent, AbilityTags, AbilityTask, AbilityTaskDef, AbilityTaskOnFinished,
-    AbilityTaskOnFinishedDef, AttributeId, EffectContext, EffectDurationTicks, GameplayAbility,
-    GameplayEffect, GameplayTagContainer, Modifier, ModifierMagnitude,
-    ModifierMagnitudeCalculation, ModifierOperation, StackingPolicy,
+    AbilitySystemComponent, AbilitySystemParams, AbilityTags, AbilityTask, AbilityTaskDef,
+    AbilityTaskOnFinished, AbilityTaskOnFinishedDef, ActiveGameplayEffect, AttributeId,
+    EffectApplicationError, EffectContext, EffectDurationTicks, GameplayAbility, GameplayEffect,
+    GameplayEffectGrantedAbility, GameplayTagContainer, GrantedAbilityRemovalPolicy, Modifier,
+    ModifierMagnitude, ModifierMagnitudeCalculation, ModifierOperation, StackingPolicy,
+    end_ability, remove_active_effect,
 };
 use std::sync::Arc;
 
@@ -763,3 +766,203 @@ fn ability_chain_context_rejects_depth_beyond_limit() {
         }
     );
 }
+
+fn lingering_ability() -> Arc<GameplayAbility> {
+    Arc::new(GameplayAbility::new(
+        AbilityTags::default(),
+        Vec::new(),
+        None,
+        None,
+        Vec::new(),
+        false,
+        false,
+    ))
+}
+
+fn granting_effect(
+    ability: Arc<GameplayAbility>,
+    removal_policy: GrantedAbilityRemovalPolicy,
+) -> Arc<GameplayEffect> {
+    Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::Infinite,
+            None,
+            1.0,
+            StackingPolicy::non_stacking(),
+            super::common_test::empty_effect_tags(),
+        )
+        .with_granted_ability(
+            GameplayEffectGrantedAbility::new(ability, 2)
+                .with_input_id(7)
+                .with_removal_policy(removal_policy),
+        ),
+    )
+}
+
+fn granted_spec_handle(app: &App, effect_handle: Entity) -> AbilitySpecHandle {
+    app.world()
+        .get::<ActiveGameplayEffect>(effect_handle)
+        .unwrap()
+        .get_granted_abilities()[0]
+        .0
+}
+
+fn remove_effect(app: &mut App, handle: Entity) {
+    app.world_mut()
+        .run_system_once(move |mut params: AbilitySystemParams| {
+            remove_active_effect(handle, &mut params)
+        })
+        .unwrap();
+}
+
+fn has_ability_spec(app: &App, owner: Entity, handle: AbilitySpecHandle) -> bool {
+    app.world()
+        .get::<AbilitySystemComponent>(owner)
+        .unwrap()
+        .find_ability_spec(handle)
+        .is_some()
+}
+
+#[test]
+fn active_effect_grants_ability_until_removed() {
+    let mut app = test_app();
+    let owner = app
+        .world_mut()
+        .spawn(AbilitySystemComponent::default())
+        .id();
+    let effect = granting_effect(
+        lingering_ability(),
+        GrantedAbilityRemovalPolicy::CancelImmediately,
+    );
+
+    assert!(super::common_test::apply_effect(
+        &mut app, owner, owner, effect
+    ));
+    app.update();
+    let effect_handle = super::common_test::active_effect_handles(&app, owner)[0];
+    let spec_handle = granted_spec_handle(&app, effect_handle);
+    let spec = app
+        .world()
+        .get::<AbilitySystemComponent>(owner)
+        .unwrap()
+        .find_ability_spec(spec_handle)
+        .unwrap();
+    assert_eq!(spec.get_level(), 2);
+    assert_eq!(spec.get_input_id(), Some(7));
+
+    assert!(activate_ability(&mut app, owner, owner, spec_handle));
+    remove_effect(&mut app, effect_handle);
+    assert_eq!(active_ability_count(&mut app), 1);
+    run_finished_ability_cleanup(&mut app);
+    assert_eq!(active_ability_count(&mut app), 0);
+    assert!(!has_ability_spec(&app, owner, spec_handle));
+}
+
+#[test]
+fn remove_on_end_granted_ability_outlives_effect_until_it_ends() {
+    let mut app = test_app();
+    let owner = app
+        .world_mut()
+        .spawn(AbilitySystemComponent::default())
+        .id();
+    let effect = granting_effect(
+        lingering_ability(),
+        GrantedAbilityRemovalPolicy::RemoveOnEnd,
+    );
+
+    assert!(super::common_test::apply_effect(
+        &mut app, owner, owner, effect
+    ));
+    app.update();
+    let effect_handle = super::common_test::active_effect_handles(&app, owner)[0];
+    let spec_handle = granted_spec_handle(&app, effect_handle);
+    assert!(activate_ability(&mut app, owner, owner, spec_handle));
+
+    remove_effect(&mut app, effect_handle);
+    run_finished_ability_cleanup(&mut app);
+    assert_eq!(active_ability_count(&mut app), 1);
+    assert!(has_ability_spec(&app, owner, spec_handle));
+    assert!(matches!(
+        activate_ability_result(&mut app, owner, owner, spec_handle),
+        Err(AbilityActivationError::AbilityNotFound { .. })
+    ));
+
+    let active_handle = active_ability_entity_for_spec(&mut app, spec_handle).unwrap();
+    app.world_mut()
+        .run_system_once(move |mut params: AbilitySystemParams| {
+            end_ability(owner, active_handle, &mut params)
+        })
+        .unwrap();
+    run_finished_ability_cleanup(&mut app);
+    assert!(!has_ability_spec(&app, owner, spec_handle));
+}
+
+#[test]
+fn granting_effect_requires_target_ability_system_component() {
+    let mut app = test_app();
+    let target = app.world_mut().spawn_empty().id();
+    let effect = granting_effect(
+        lingering_ability(),
+        GrantedAbilityRemovalPolicy::CancelImmediately,
+    );
+
+    assert_eq!(
+        super::common_test::apply_effect_result(
+            &mut app,
+            target,
+            effect,
+            bevy_tools::EffectPayload::new(target, None, 1),
+        ),
+        Err(EffectApplicationError::MissingAbilitySystemComponent { target })
+    );
+}
+
+#[test]
+fn rebuilt_effect_swaps_granted_abilities_for_the_new_definition() {
+    let mut app = test_app();
+    let owner = app
+        .world_mut()
+        .spawn(AbilitySystemComponent::default())
+        .id();
+    let old_effect = granting_effect(
+        lingering_ability(),
+        GrantedAbilityRemovalPolicy::CancelImmediately,
+    );
+    let new_effect = granting_effect(
+        lingering_ability(),
+        GrantedAbilityRemovalPolicy::CancelImmediately,
+    );
+
+    assert!(super::common_test::apply_effect(
+        &mut app,
+        owner,
+        owner,
+        old_effect.clone()
+    ));
+    let effect_handle = super::common_test::active_effect_handles(&app, owner)[0];
+    let old_spec_handle = granted_spec_handle(&app, effect_handle);
+    assert!(activate_ability(&mut app, owner, owner, old_spec_handle));
+
+    let new_def = new_effect.clone();
+    app.world_mut()
+        .run_system_once(move |mut params: AbilitySystemParams| {
+            bevy_tools::rebuild_active_effects_from_definition(&old_effect, &new_def, &mut params)
+        })
+        .unwrap();
+    let new_spec_handle = granted_spec_handle(&app, effect_handle);
+    let ability_system = app.world().get::<AbilitySystemComponent>(owner).unwrap();
+    assert!(Arc::ptr_eq(
+        ability_system
+            .find_ability_spec(new_spec_handle)
+            .unwrap()
+            .get_ability(),
+        new_effect.get_granted_abilities()[0].get_ability()
+    ));
+
+    // The old grant is cancelled and cleared like on removal.
+    run_finished_ability_cleanup(&mut app);
+    assert_eq!(active_ability_count(&mut app), 0);
+    assert!(!has_ability_spec(&app, owner, old_spec_handle));
+    assert!(has_ability_spec(&app, owner, new_spec_handle));
+}
@@ -0,0 +1,104 @@
+use super::common_test::{
+    add_modifier, apply_effect, current_value, empty_effect_tags, register_attribute, test_app,
+};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_tools::attributes::{AttributeClamp, AttributeIdRegister, GameplayAttributes};
+use bevy_tools::{EffectDurationTicks, GameplayEffect, StackingPolicy};
+use std::sync::Arc;
+
+#[derive(GameplayAttributes)]
+struct CombatStats {
+    #[attribute(clamp(min = 0.0, max = 100.0))]
+    health: f64,
+    max_health: f64,
+    #[attribute(name = "Combat.Armor", clamp(min = 0.0))]
+    armor: f64,
+}
+
+impl Default for CombatStats {
+    fn default() -> Self {
+        Self {
+            health: 80.0,
+            max_health: 100.0,
+            armor: 5.0,
+        }
+    }
+}
+
+fn register_combat_stats(app: &mut App) -> CombatStatsAttributeIds {
+    app.world_mut()
+        .run_system_once(|mut register: AttributeIdRegister| {
+            CombatStats::register_attribute_ids(&mut register).unwrap()
+        })
+        .unwrap()
+}
+
+#[test]
+fn derived_attributes_register_ids_by_field_name_or_override() {
+    let mut app = test_app();
+    let ids = register_combat_stats(&mut app);
+
+    assert_eq!(ids.health, register_attribute(&mut app, "Health"));
+    assert_eq!(ids.max_health, register_attribute(&mut app, "MaxHealth"));
+    assert_eq!(ids.armor, register_attribute(&mut app, "Combat.Armor"));
+    assert_eq!(ids, register_combat_stats(&mut app));
+}
+
+#[test]
+fn derived_attributes_initialize_set_with_defaults_and_clamps() {
+    let mut app = test_app();
+    let ids = register_combat_stats(&mut app);
+    let mut attributes = CombatStats::default().make_attribute_set(&ids);
+
+    assert_eq!(ids.get_health(&mut attributes), Some(80.0));
+    assert_eq!(ids.get_max_health_base(&attributes), Some(100.0));
+    assert_eq!(
+        attributes.get_attribute_clamp(ids.health),
+        Some(AttributeClamp::Range {
+            min: Some(0.0),
+            max: Some(100.0),
+        })
+    );
+    assert_eq!(
+        attributes.get_attribute_clamp(ids.armor),
+        Some(AttributeClamp::Range {
+            min: Some(0.0),
+            max: None,
+        })
+    );
+    assert_eq!(
+        attributes.get_attribute_clamp(ids.max_health),
+        Some(AttributeClamp::None)
+    );
+}
+
+#[test]
+fn derived_attributes_read_back_current_values() {
+    let mut app = test_app();
+    let ids = register_combat_stats(&mut app);
+    let target = app
+        .world_mut()
+        .spawn(CombatStats::default().make_attribute_set(&ids))
+        .id();
+    let effect = Arc::new(GameplayEffect::new(
+        vec![
+            add_modifier(ids.health, 50.0),
+            add_modifier(ids.armor, -10.0),
+        ],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect(&mut app, target, target, effect));
+    assert_eq!(current_value(&mut app, target, ids.health), 100.0);
+
+    let mut entity = app.world_mut().entity_mut(target);
+    let stats = CombatStats::from_attribute_set(&ids, &mut entity.get_mut().unwrap());
+    assert_eq!(stats.health, 100.0);
+    assert_eq!(stats.max_health, 100.0);
+    assert_eq!(stats.armor, 0.0);
+}
@@ -1,15 +1,23 @@
 use super::common_test::{
-    active_effect_handles, add_tag_to_entity, apply_effect, attribute_set, current_value,
-    effect_tags, empty_effect_tags, register_attribute, register_tag, run_effect_duration_tick,
+    active_effect_handles, add_tag_to_entity, apply_effect, apply_effect_result, attribute_set,
+    current_value, effect_tags, empty_effect_tags, register_attribute, register_tag,
+    remove_tag_from_entity, run_effect_application_queue, run_effect_duration_tick,
     run_effect_period_tick, run_effect_tag_requirements_update, test_app,
 };
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
 use bevy_tools::{
-    ActiveGameplayEffect, EffectDurationTicks, EffectPeriodTicks, EffectTags, GameplayEffect,
-    GameplayEffectImmunityQuery, GameplayTag, GameplayTagContainer, ModifierMagnitude,
-    StackDurationPolicy, StackExpirationPolicy, StackMagnitudePolicy, StackOverflowPolicy,
-    StackPeriodPolicy, StackingPolicy, StackingType, TagRequirements,
+    ActiveEffectDurationTicks, ActiveGameplayEffect, CurveInterpolation, CurveKey,
+    EffectApplicationError, EffectDurationAdjustment, EffectDurationTicks, EffectPayload,
+    EffectPeriodTicks, EffectTags, GameplayEffect, GameplayEffectImmunityQuery,
+    GameplayEffectLifecycle, GameplayEffectLifecycleEvent, GameplayEffectRejectedEvent,
+    GameplayEffectRejectionReason, GameplayTag, GameplayTagContainer, Modifier, ModifierMagnitude,
+    ModifierOperation, RealCurve, SetByCallerKey, SetByCallerMagnitude, StackDurationPolicy,
+    StackExpirationPolicy, StackMagnitudeCalculation, StackMagnitudePolicy, StackOverflowPolicy,
+    StackPeriodPolicy, StackingPolicy, StackingType, TagRequirements, seconds_to_ticks,
 };
 use std::sync::Arc;
+use std::time::Duration;
 
 fn tags_with_requirements(
     granted_tags: Vec<GameplayTag>,
@@ -98,6 +106,7 @@ fn linear_stacking_respects_stack_limit() {
 #[test]
 fn remove_single_stack_expiration_decrements_stack_before_removal() {
     let mut app = test_app();
+    record_lifecycles(&mut app);
     let armor = register_attribute(&mut app, "Armor");
     let target = app
         .world_mut()
@@ -140,6 +149,15 @@ fn remove_single_stack_expiration_decrements_stack_before_removal() {
     run_effect_duration_tick(&mut app);
     assert_eq!(current_value(&mut app, target, armor), 10.0);
     assert!(active_effect_handles(&app, target).is_empty());
+    assert_eq!(
+        recorded_lifecycles(&app),
+        vec![
+            GameplayEffectLifecycle::Applied,
+            GameplayEffectLifecycle::Stacked { stack_count: 2 },
+            GameplayEffectLifecycle::Unstacked { stack_count: 1 },
+            GameplayEffectLifecycle::Expired,
+        ]
+    );
 }
 
 #[test]
@@ -399,10 +417,66 @@ fn active_immunity_blocks_matching_incoming_effect() {
     ));
 
     assert!(apply_effect(&mut app, target, source, immunity_effect));
-    assert!(!apply_effect(&mut app, target, source, incoming));
+    let immunity_handle = active_effect_handles(&app, target)[0];
+    assert_eq!(
+        apply_effect_result(
+            &mut app,
+            target,
+            incoming,
+            EffectPayload::new(source, None, 1)
+        ),
+        Err(EffectApplicationError::BlockedByImmunity {
+            handle: immunity_handle,
+            query_index: 0,
+        })
+    );
     assert_eq!(current_value(&mut app, target, health), 100.0);
 }
 
+#[test]
+fn failed_applications_return_typed_errors() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let tagless_target = app.world_mut().spawn_empty().id();
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(
+            health,
+            100.0,
+            bevy_tools::AttributeClamp::None,
+        ))
+        .id();
+    let zero_duration = Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(health, 5.0)],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(0.0)),
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert_eq!(
+        apply_effect_result(
+            &mut app,
+            tagless_target,
+            super::common_test::instant_add_effect(health, 5.0),
+            EffectPayload::new(target, None, 1)
+        ),
+        Err(EffectApplicationError::MissingAttributeSet {
+            target: tagless_target,
+        })
+    );
+    assert!(matches!(
+        apply_effect_result(
+            &mut app,
+            target,
+            zero_duration,
+            EffectPayload::new(target, None, 1)
+        ),
+        Err(EffectApplicationError::InvalidSpec { .. })
+    ));
+}
+
 #[test]
 fn ongoing_tag_requirements_inhibit_and_restore_active_effect() {
     let mut app = test_app();
@@ -495,6 +569,53 @@ fn removal_tag_requirement_cleans_up_active_effect() {
     assert!(active_effect_handles(&app, target).is_empty());
 }
 
+#[test]
+fn modifier_target_tag_requirements_toggle_only_that_modifier() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let armor = register_attribute(&mut app, "Armor");
+    let burning = register_tag(&mut app, "State.Burning");
+    let target = app
+        .world_mut()
+        .spawn((
+            GameplayTagContainer::default(),
+            attribute_set(power, 10.0, bevy_tools::AttributeClamp::None),
+        ))
+        .id();
+    app.world_mut()
+        .entity_mut(target)
+        .get_mut::<bevy_tools::AttributeSet>()
+        .unwrap()
+        .initialize_attribute(armor, 5.0, None, bevy_tools::AttributeClamp::None);
+    let effect = Arc::new(GameplayEffect::new(
+        vec![
+            super::common_test::add_modifier(power, 10.0)
+                .with_target_tag_requirements(TagRequirements::new(vec![burning], Vec::new())),
+            super::common_test::add_modifier(armor, 3.0),
+        ],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect(&mut app, target, target, effect));
+    assert_eq!(current_value(&mut app, target, power), 10.0);
+    assert_eq!(current_value(&mut app, target, armor), 8.0);
+
+    add_tag_to_entity(&mut app, target, burning);
+    run_effect_tag_requirements_update(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 20.0);
+    assert_eq!(current_value(&mut app, target, armor), 8.0);
+
+    remove_tag_from_entity(&mut app, target, burning);
+    run_effect_tag_requirements_update(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 10.0);
+    assert_eq!(current_value(&mut app, target, armor), 8.0);
+    assert_eq!(active_effect_handles(&app, target).len(), 1);
+}
+
 #[test]
 fn tag_granting_effect_without_tag_container_rolls_back_modifiers() {
     let mut app = test_app();
@@ -517,3 +638,858 @@ fn tag_granting_effect_without_tag_container_rolls_back_modifiers() {
     assert_eq!(current_value(&mut app, target, power), 10.0);
     assert!(active_effect_handles(&app, target).is_empty());
 }
+
+#[derive(Resource, Default)]
+struct LifecycleLog {
+    lifecycles: Vec<(Option<Entity>, GameplayEffectLifecycle)>,
+    rejections: Vec<(Option<Entity>, GameplayEffectRejectionReason)>,
+}
+
+fn record_lifecycles(app: &mut App) {
+    app.init_resource::<LifecycleLog>().add_observer(
+        |event: On<GameplayEffectLifecycleEvent>, mut log: ResMut<LifecycleLog>| {
+            log.lifecycles
+                .push((event.get_handle(), event.get_lifecycle()));
+        },
+    );
+}
+
+fn recorded_lifecycles(app: &App) -> Vec<GameplayEffectLifecycle> {
+    app.world()
+        .resource::<LifecycleLog>()
+        .lifecycles
+        .iter()
+        .map(|(_, lifecycle)| *lifecycle)
+        .collect()
+}
+
+#[test]
+fn lifecycle_and_rejected_events_report_handle_and_reason() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    app.init_resource::<LifecycleLog>()
+        .add_observer(
+            |event: On<GameplayEffectLifecycleEvent>, mut log: ResMut<LifecycleLog>| {
+                log.lifecycles
+                    .push((event.get_handle(), event.get_lifecycle()));
+            },
+        )
+        .add_observer(
+            |event: On<GameplayEffectRejectedEvent>, mut log: ResMut<LifecycleLog>| {
+                log.rejections
+                    .push((event.get_handle(), event.get_reason()));
+            },
+        );
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(
+            health,
+            100.0,
+            bevy_tools::AttributeClamp::None,
+        ))
+        .id();
+    let stacking_effect = Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(health, 5.0)],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(1.0)),
+        None,
+        1.0,
+        StackingPolicy::linear_refreshing(StackingType::AggregateByTarget, 2),
+        empty_effect_tags(),
+    ));
+    let unlucky_effect = Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(health, 5.0)],
+        EffectDurationTicks::Instant,
+        None,
+        0.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect(
+        &mut app,
+        target,
+        target,
+        stacking_effect.clone()
+    ));
+    let handle = active_effect_handles(&app, target)[0];
+    assert!(apply_effect(
+        &mut app,
+        target,
+        target,
+        stacking_effect.clone()
+    ));
+    assert!(!apply_effect(&mut app, target, target, stacking_effect));
+    assert!(!apply_effect(&mut app, target, target, unlucky_effect));
+    run_effect_duration_tick(&mut app);
+
+    let log = app.world().resource::<LifecycleLog>();
+    assert_eq!(
+        log.lifecycles,
+        vec![
+            (Some(handle), GameplayEffectLifecycle::Applied),
+            (
+                Some(handle),
+                GameplayEffectLifecycle::Stacked { stack_count: 2 }
+            ),
+            (Some(handle), GameplayEffectLifecycle::Expired),
+        ]
+    );
+    assert_eq!(
+        log.rejections,
+        vec![
+            (Some(handle), GameplayEffectRejectionReason::StackOverflow),
+            (None, GameplayEffectRejectionReason::Probability),
+        ]
+    );
+}
+
+#[test]
+fn seconds_round_up_to_whole_fixed_ticks() {
+    let sixtieth = Duration::from_secs_f64(1.0 / 60.0);
+    assert_eq!(seconds_to_ticks(1.0, sixtieth), 60);
+    assert_eq!(seconds_to_ticks(0.5, sixtieth), 30);
+    assert_eq!(seconds_to_ticks(1.0, Duration::from_millis(300)), 4);
+    assert_eq!(seconds_to_ticks(0.001, Duration::from_millis(250)), 1);
+    assert_eq!(seconds_to_ticks(0.0, sixtieth), 0);
+    assert_eq!(seconds_to_ticks(1.0, Duration::ZERO), 0);
+}
+
+#[test]
+fn duration_in_seconds_uses_fixed_timestep_and_reports_both_units() {
+    let mut app = test_app();
+    let timestep = Duration::from_millis(250);
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .set_timestep(timestep);
+    let power = register_attribute(&mut app, "Power");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(power, 10.0, bevy_tools::AttributeClamp::None))
+        .id();
+    let effect = Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(power, 5.0)],
+        EffectDurationTicks::DurationSeconds(ModifierMagnitude::Flat(1.1)),
+        Some(EffectPeriodTicks::from_seconds(
+            ModifierMagnitude::Flat(0.5),
+            false,
+        )),
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect(&mut app, target, target, effect));
+    let handle = active_effect_handles(&app, target)[0];
+    let remaining = |app: &App| {
+        let duration = app
+            .world()
+            .entity(handle)
+            .get::<ActiveEffectDurationTicks>()
+            .unwrap();
+        (
+            duration.get_remain_ticks(),
+            duration.get_remain_seconds(timestep),
+        )
+    };
+    // 1.1s / 0.25s = 4.4, rounded up to 5 ticks.
+    assert_eq!(remaining(&app), (5, 1.25));
+
+    run_effect_duration_tick(&mut app);
+    assert_eq!(remaining(&app), (4, 1.0));
+    run_effect_period_tick(&mut app);
+    run_effect_period_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 15.0);
+}
+
+#[test]
+fn set_by_caller_magnitudes_read_payload_and_reject_missing_keys() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let armor = register_attribute(&mut app, "Armor");
+    let damage_key = register_tag(&mut app, "Data.Damage");
+    let armor_key = app
+        .world_mut()
+        .resource_mut::<bevy_tools::UniqueNamePool>()
+        .new_name("ArmorShred");
+    let mut target_attributes = attribute_set(health, 100.0, bevy_tools::AttributeClamp::None);
+    target_attributes.initialize_attribute(armor, 20.0, None, bevy_tools::AttributeClamp::None);
+    let target = app.world_mut().spawn(target_attributes).id();
+    let effect = Arc::new(GameplayEffect::new(
+        vec![
+            Modifier::new(
+                health,
+                ModifierOperation::Add,
+                ModifierMagnitude::SetByCaller(SetByCallerMagnitude::new(damage_key)),
+            ),
+            Modifier::new(
+                armor,
+                ModifierOperation::Add,
+                ModifierMagnitude::SetByCaller(
+                    SetByCallerMagnitude::new(armor_key).with_fallback(-1.0),
+                ),
+            ),
+        ],
+        EffectDurationTicks::Instant,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(matches!(
+        apply_effect_result(
+            &mut app,
+            target,
+            effect.clone(),
+            EffectPayload::new(target, None, 1),
+        ),
+        Err(EffectApplicationError::MissingSetByCallerMagnitude {
+            key: SetByCallerKey::Tag(key),
+        }) if key == damage_key
+    ));
+    assert_eq!(current_value(&mut app, target, health), 100.0);
+
+    let payload = EffectPayload::new(target, None, 1)
+        .with_set_by_caller_magnitude(damage_key, -10.0)
+        .with_set_by_caller_magnitude(damage_key, -30.0);
+    assert_eq!(payload.get_set_by_caller_magnitudes().len(), 1);
+    assert!(apply_effect_result(&mut app, target, effect.clone(), payload).is_ok());
+    assert_eq!(current_value(&mut app, target, health), 70.0);
+    assert_eq!(current_value(&mut app, target, armor), 19.0);
+
+    let payload = EffectPayload::new(target, None, 1)
+        .with_set_by_caller_magnitude(damage_key, 0.0)
+        .with_set_by_caller_magnitude(armor_key, -5.0);
+    assert!(apply_effect_result(&mut app, target, effect, payload).is_ok());
+    assert_eq!(current_value(&mut app, target, armor), 14.0);
+}
+
+#[test]
+fn rebuilt_active_effect_swaps_modifiers_tags_and_stacks_with_new_definition() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let burning = register_tag(&mut app, "State.Burning");
+    let frozen = register_tag(&mut app, "State.Frozen");
+    let target = app
+        .world_mut()
+        .spawn((
+            attribute_set(power, 10.0, bevy_tools::AttributeClamp::None),
+            GameplayTagContainer::default(),
+        ))
+        .id();
+    let stacking = StackingPolicy::new(
+        StackingType::AggregateByTarget,
+        3,
+        StackMagnitudePolicy::Linear,
+        StackDurationPolicy::KeepExisting,
+        StackPeriodPolicy::KeepCurrentTick,
+        StackOverflowPolicy::RejectApplication,
+        StackExpirationPolicy::RemoveAllStacks,
+    );
+    let effect_with = |value: f64, ticks: f64, granted_tag: GameplayTag| {
+        Arc::new(GameplayEffect::new(
+            vec![super::common_test::add_modifier(power, value)],
+            EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(ticks)),
+            None,
+            1.0,
+            stacking.clone(),
+            effect_tags(Vec::new(), vec![granted_tag]),
+        ))
+    };
+    let old_effect = effect_with(5.0, 10.0, burning);
+    let new_effect = effect_with(2.0, 4.0, frozen);
+
+    assert!(apply_effect(&mut app, target, target, old_effect.clone()));
+    assert!(apply_effect(&mut app, target, target, old_effect.clone()));
+    run_effect_duration_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 20.0);
+
+    let (old_def, new_def) = (old_effect.clone(), new_effect.clone());
+    let rebuilt = app
+        .world_mut()
+        .run_system_once(move |mut params: bevy_tools::AbilitySystemParams| {
+            bevy_tools::rebuild_active_effects_from_definition(&old_def, &new_def, &mut params)
+        })
+        .unwrap();
+    assert_eq!(rebuilt, active_effect_handles(&app, target));
+    assert_eq!(current_value(&mut app, target, power), 14.0);
+
+    let handle = rebuilt[0];
+    let world = app.world();
+    assert!(Arc::ptr_eq(
+        world
+            .get::<ActiveGameplayEffect>(handle)
+            .unwrap()
+            .get_spec()
+            .get_def(),
+        &new_effect
+    ));
+    assert_eq!(
+        world
+            .get::<ActiveEffectDurationTicks>(handle)
+            .unwrap()
+            .get_remain_ticks(),
+        4
+    );
+    let tags = world.get::<GameplayTagContainer>(target).unwrap();
+    assert!(!tags.has_tag(&burning));
+    assert!(tags.has_tag(&frozen));
+
+    assert!(apply_effect(&mut app, target, target, new_effect));
+    assert_eq!(active_effect_handles(&app, target), vec![handle]);
+    assert_eq!(current_value(&mut app, target, power), 16.0);
+}
+
+fn stacking_add_effect(
+    attribute: bevy_tools::AttributeId,
+    value: f64,
+    asset_tags: Vec<GameplayTag>,
+) -> Arc<GameplayEffect> {
+    Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(attribute, value)],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::new(
+            StackingType::AggregateByTarget,
+            5,
+            StackMagnitudePolicy::Linear,
+            StackDurationPolicy::KeepExisting,
+            StackPeriodPolicy::KeepCurrentTick,
+            StackOverflowPolicy::RejectApplication,
+            StackExpirationPolicy::RemoveAllStacks,
+        ),
+        effect_tags(asset_tags, Vec::new()),
+    ))
+}
+
+#[test]
+fn removing_stacks_rebuilds_modifiers_and_removes_effect_at_zero() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(power, 10.0, bevy_tools::AttributeClamp::None))
+        .id();
+    let poison = stacking_add_effect(power, 5.0, Vec::new());
+    for _ in 0..3 {
+        assert!(apply_effect(&mut app, target, target, poison.clone()));
+        app.update();
+    }
+    let handle = active_effect_handles(&app, target)[0];
+    assert_eq!(current_value(&mut app, target, power), 25.0);
+
+    let remove_stacks = |app: &mut App, stack_count: u32| {
+        app.world_mut()
+            .run_system_once(move |mut params: bevy_tools::AbilitySystemParams| {
+                bevy_tools::remove_active_effect_stacks(handle, stack_count, &mut params)
+            })
+            .unwrap()
+    };
+    assert_eq!(remove_stacks(&mut app, 2), 2);
+    assert_eq!(
+        app.world()
+            .get::<ActiveGameplayEffect>(handle)
+            .unwrap()
+            .get_stack_count(),
+        1
+    );
+    assert_eq!(current_value(&mut app, target, power), 15.0);
+
+    assert_eq!(remove_stacks(&mut app, 5), 1);
+    assert!(active_effect_handles(&app, target).is_empty());
+    assert_eq!(current_value(&mut app, target, power), 10.0);
+    assert_eq!(remove_stacks(&mut app, 1), 0);
+}
+
+#[test]
+fn removing_stacks_with_tags_reports_total_removed() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let armor = register_attribute(&mut app, "Armor");
+    let debuff = register_tag(&mut app, "Effect.Debuff");
+    let mut attributes = attribute_set(power, 10.0, bevy_tools::AttributeClamp::None);
+    attributes.initialize_attribute(armor, 10.0, None, bevy_tools::AttributeClamp::None);
+    let target = app.world_mut().spawn(attributes).id();
+    let weaken = stacking_add_effect(power, -1.0, vec![debuff]);
+    let sunder = stacking_add_effect(armor, -2.0, vec![debuff]);
+    let buff = stacking_add_effect(power, 3.0, Vec::new());
+    for effect in [&weaken, &weaken, &sunder, &sunder, &buff] {
+        assert!(apply_effect(&mut app, target, target, effect.clone()));
+        app.update();
+    }
+    assert_eq!(current_value(&mut app, target, power), 11.0);
+    assert_eq!(current_value(&mut app, target, armor), 6.0);
+
+    let removed = app
+        .world_mut()
+        .run_system_once(move |mut params: bevy_tools::AbilitySystemParams| {
+            bevy_tools::remove_active_effect_stacks_with_tags(target, &[debuff], 1, &mut params)
+        })
+        .unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(active_effect_handles(&app, target).len(), 3);
+    assert_eq!(current_value(&mut app, target, power), 12.0);
+    assert_eq!(current_value(&mut app, target, armor), 8.0);
+}
+
+fn chill_and_frozen(
+    app: &mut App,
+    overflow_policy: StackOverflowPolicy,
+    clear_stacks_on_overflow: bool,
+) -> (Arc<GameplayEffect>, GameplayTag) {
+    let frozen_tag = register_tag(app, "State.Frozen");
+    let frozen = Arc::new(GameplayEffect::new(
+        Vec::new(),
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        effect_tags(Vec::new(), vec![frozen_tag]),
+    ));
+    let stacking = StackingPolicy::new(
+        StackingType::AggregateByTarget,
+        3,
+        StackMagnitudePolicy::Linear,
+        StackDurationPolicy::RefreshOnSuccessfulStack,
+        StackPeriodPolicy::KeepCurrentTick,
+        overflow_policy,
+        StackExpirationPolicy::RemoveAllStacks,
+    );
+    let stacking = if clear_stacks_on_overflow {
+        stacking.with_clear_stacks_on_overflow()
+    } else {
+        stacking
+    };
+    let chill = Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(5.0)),
+            None,
+            1.0,
+            stacking,
+            empty_effect_tags(),
+        )
+        .with_overflow_effect(frozen),
+    );
+    (chill, frozen_tag)
+}
+
+fn spawn_chill_target(app: &mut App) -> Entity {
+    let health = register_attribute(app, "Health");
+    app.world_mut()
+        .spawn((
+            attribute_set(health, 100.0, bevy_tools::AttributeClamp::None),
+            GameplayTagContainer::default(),
+        ))
+        .id()
+}
+
+fn has_tag(app: &App, entity: Entity, tag: GameplayTag) -> bool {
+    app.world()
+        .get::<GameplayTagContainer>(entity)
+        .unwrap()
+        .has_tag(&tag)
+}
+
+#[test]
+fn overflow_applies_overflow_effects_and_clears_stacks() {
+    let mut app = test_app();
+    let target = spawn_chill_target(&mut app);
+    let (chill, frozen_tag) =
+        chill_and_frozen(&mut app, StackOverflowPolicy::RejectApplication, true);
+
+    for _ in 0..3 {
+        assert!(apply_effect(&mut app, target, target, chill.clone()));
+    }
+    run_effect_application_queue(&mut app);
+    assert!(!has_tag(&app, target, frozen_tag));
+
+    assert!(apply_effect(&mut app, target, target, chill));
+    assert!(active_effect_handles(&app, target).is_empty());
+    run_effect_application_queue(&mut app);
+    assert!(has_tag(&app, target, frozen_tag));
+    assert_eq!(active_effect_handles(&app, target).len(), 1);
+}
+
+#[test]
+fn overflow_keeps_stacks_and_refreshes_or_denies_by_policy() {
+    for (overflow_policy, rejected, expected_remaining) in [
+        (StackOverflowPolicy::RefreshDuration, false, 5),
+        (StackOverflowPolicy::RejectApplication, true, 3),
+    ] {
+        let mut app = test_app();
+        app.init_resource::<LifecycleLog>().add_observer(
+            |event: On<GameplayEffectRejectedEvent>, mut log: ResMut<LifecycleLog>| {
+                log.rejections
+                    .push((event.get_handle(), event.get_reason()));
+            },
+        );
+        let target = spawn_chill_target(&mut app);
+        let (chill, frozen_tag) = chill_and_frozen(&mut app, overflow_policy, false);
+
+        for _ in 0..3 {
+            assert!(apply_effect(&mut app, target, target, chill.clone()));
+        }
+        run_effect_duration_tick(&mut app);
+        run_effect_duration_tick(&mut app);
+        let handle = active_effect_handles(&app, target)[0];
+        let result =
+            apply_effect_result(&mut app, target, chill, EffectPayload::new(target, None, 1));
+        if rejected {
+            assert_eq!(
+                result,
+                Err(EffectApplicationError::StackLimitReached {
+                    handle,
+                    stack_limit: 3
+                })
+            );
+            assert_eq!(
+                app.world().resource::<LifecycleLog>().rejections,
+                vec![(Some(handle), GameplayEffectRejectionReason::StackOverflow)]
+            );
+        } else {
+            assert_eq!(result, Ok(()));
+            assert!(app.world().resource::<LifecycleLog>().rejections.is_empty());
+        }
+
+        let world = app.world();
+        assert_eq!(
+            world
+                .get::<ActiveGameplayEffect>(handle)
+                .unwrap()
+                .get_stack_count(),
+            3
+        );
+        assert_eq!(
+            world
+                .get::<ActiveEffectDurationTicks>(handle)
+                .unwrap()
+                .get_remain_ticks(),
+            expected_remaining
+        );
+        run_effect_application_queue(&mut app);
+        assert!(has_tag(&app, target, frozen_tag));
+    }
+}
+
+#[test]
+fn denied_overflow_keeps_effects_matched_by_remove_effects_with_tags() {
+    let mut app = test_app();
+    let target = spawn_chill_target(&mut app);
+    let (chill, frozen_tag) =
+        chill_and_frozen(&mut app, StackOverflowPolicy::RejectApplication, false);
+    let warmth_tag = register_tag(&mut app, "Effect.Buff.Warmth");
+    let warmth = Arc::new(GameplayEffect::new(
+        Vec::new(),
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        effect_tags(vec![warmth_tag], Vec::new()),
+    ));
+    let chill_tags = EffectTags::new(
+        Vec::new(),
+        Vec::new(),
+        TagRequirements::default(),
+        TagRequirements::default(),
+        TagRequirements::default(),
+        TagRequirements::default(),
+        TagRequirements::default(),
+        TagRequirements::default(),
+        Vec::new(),
+        vec![warmth_tag],
+    );
+    let chill = Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(5.0)),
+            None,
+            1.0,
+            StackingPolicy::new(
+                StackingType::AggregateByTarget,
+                3,
+                StackMagnitudePolicy::Linear,
+                StackDurationPolicy::RefreshOnSuccessfulStack,
+                StackPeriodPolicy::KeepCurrentTick,
+                StackOverflowPolicy::RejectApplication,
+                StackExpirationPolicy::RemoveAllStacks,
+            ),
+            chill_tags,
+        )
+        .with_overflow_effect(chill.get_overflow_effects()[0].clone()),
+    );
+
+    for _ in 0..3 {
+        assert!(apply_effect(&mut app, target, target, chill.clone()));
+    }
+    let chill_handle = active_effect_handles(&app, target)[0];
+    assert!(apply_effect(&mut app, target, target, warmth));
+    assert_eq!(active_effect_handles(&app, target).len(), 2);
+
+    let result = apply_effect_result(&mut app, target, chill, EffectPayload::new(target, None, 1));
+    assert_eq!(
+        result,
+        Err(EffectApplicationError::StackLimitReached {
+            handle: chill_handle,
+            stack_limit: 3
+        })
+    );
+    assert_eq!(active_effect_handles(&app, target).len(), 2);
+    run_effect_application_queue(&mut app);
+    assert!(has_tag(&app, target, frozen_tag));
+}
+
+fn stacked_power_effect(
+    power: bevy_tools::AttributeId,
+    period: Option<EffectPeriodTicks>,
+    magnitude_policy: StackMagnitudePolicy,
+) -> Arc<GameplayEffect> {
+    Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(power, 10.0)],
+        EffectDurationTicks::Infinite,
+        period,
+        1.0,
+        StackingPolicy::new(
+            StackingType::AggregateByTarget,
+            3,
+            magnitude_policy,
+            StackDurationPolicy::KeepExisting,
+            StackPeriodPolicy::KeepCurrentTick,
+            StackOverflowPolicy::RejectApplication,
+            StackExpirationPolicy::RemoveAllStacks,
+        ),
+        empty_effect_tags(),
+    ))
+}
+
+#[test]
+fn non_linear_stack_magnitude_policies_scale_duration_modifiers() {
+    let curve = Arc::new(RealCurve::new(
+        vec![
+            CurveKey::new(1.0, 1.0),
+            CurveKey::new(2.0, 1.8),
+            CurveKey::new(3.0, 2.4),
+        ],
+        CurveInterpolation::Linear,
+    ));
+    for (magnitude_policy, expected) in [
+        (
+            StackMagnitudePolicy::Diminishing {
+                additional_stack_scale: 0.5,
+            },
+            [110.0, 115.0, 120.0],
+        ),
+        (StackMagnitudePolicy::Curve(curve), [110.0, 118.0, 124.0]),
+    ] {
+        let mut app = test_app();
+        let power = register_attribute(&mut app, "Power");
+        let target = app
+            .world_mut()
+            .spawn(attribute_set(
+                power,
+                100.0,
+                bevy_tools::AttributeClamp::None,
+            ))
+            .id();
+        let effect = stacked_power_effect(power, None, magnitude_policy);
+
+        for expected_value in expected {
+            assert!(apply_effect(&mut app, target, target, effect.clone()));
+            assert_eq!(current_value(&mut app, target, power), expected_value);
+        }
+    }
+}
+
+struct SquaredStacks;
+
+impl StackMagnitudeCalculation for SquaredStacks {
+    fn calculate(&self, stack_count: u32) -> f64 {
+        (stack_count * stack_count) as f64
+    }
+}
+
+#[test]
+fn custom_stack_magnitude_policy_scales_periodic_instant_modifiers() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(power, 0.0, bevy_tools::AttributeClamp::None))
+        .id();
+    let effect = stacked_power_effect(
+        power,
+        Some(EffectPeriodTicks::new(ModifierMagnitude::Flat(1.0), false)),
+        StackMagnitudePolicy::Custom(Arc::new(SquaredStacks)),
+    );
+
+    assert!(apply_effect(&mut app, target, target, effect.clone()));
+    run_effect_period_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 10.0);
+
+    assert!(apply_effect(&mut app, target, target, effect));
+    run_effect_period_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, power), 50.0);
+}
+
+#[test]
+fn independent_stack_durations_expire_each_stack_on_its_own() {
+    let mut app = test_app();
+    record_lifecycles(&mut app);
+    let power = register_attribute(&mut app, "Power");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(power, 10.0, bevy_tools::AttributeClamp::None))
+        .id();
+    let effect = Arc::new(GameplayEffect::new(
+        vec![super::common_test::add_modifier(power, 5.0)],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(5.0)),
+        None,
+        1.0,
+        StackingPolicy::new(
+            StackingType::AggregateByTarget,
+            2,
+            StackMagnitudePolicy::Linear,
+            StackDurationPolicy::IndependentPerStack,
+            StackPeriodPolicy::KeepCurrentTick,
+            StackOverflowPolicy::RefreshDuration,
+            StackExpirationPolicy::RemoveAllStacks,
+        ),
+        empty_effect_tags(),
+    ));
+    let stack_remain_ticks = |app: &App, handle: Entity| {
+        let duration = app
+            .world()
+            .get::<ActiveEffectDurationTicks>(handle)
+            .unwrap();
+        (
+            duration.get_remain_ticks(),
+            duration.get_stack_remain_ticks().to_vec(),
+        )
+    };
+
+    assert!(apply_effect(&mut app, target, target, effect.clone()));
+    let handle = active_effect_handles(&app, target)[0];
+    run_effect_duration_tick(&mut app);
+    run_effect_duration_tick(&mut app);
+    assert!(apply_effect(&mut app, target, target, effect.clone()));
+    assert_eq!(stack_remain_ticks(&app, handle), (5, vec![3, 5]));
+    assert_eq!(current_value(&mut app, target, power), 20.0);
+
+    // At the limit, a refresh replaces the stack closest to expiring.
+    run_effect_duration_tick(&mut app);
+    assert!(apply_effect(&mut app, target, target, effect));
+    assert_eq!(stack_remain_ticks(&app, handle), (5, vec![4, 5]));
+
+    for _ in 0..4 {
+        run_effect_duration_tick(&mut app);
+    }
+    assert_eq!(stack_remain_ticks(&app, handle), (1, vec![1]));
+    assert_eq!(
+        app.world()
+            .get::<ActiveGameplayEffect>(handle)
+            .unwrap()
+            .get_stack_count(),
+        1
+    );
+    assert_eq!(current_value(&mut app, target, power), 15.0);
+    assert_eq!(
+        recorded_lifecycles(&app).last(),
+        Some(&GameplayEffectLifecycle::Unstacked { stack_count: 1 })
+    );
+
+    run_effect_duration_tick(&mut app);
+    app.update();
+    assert!(active_effect_handles(&app, target).is_empty());
+    assert_eq!(current_value(&mut app, target, power), 10.0);
+}
+
+#[test]
+fn remaining_duration_can_be_queried_and_adjusted() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let buff_tag = register_tag(&mut app, "Buff.Haste");
+    let stun_tag = register_tag(&mut app, "Control.Stun");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(power, 10.0, bevy_tools::AttributeClamp::None))
+        .id();
+    let timed_effect = |asset_tag: GameplayTag, period: Option<EffectPeriodTicks>| {
+        Arc::new(GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(10.0)),
+            period,
+            1.0,
+            StackingPolicy::non_stacking(),
+            effect_tags(vec![asset_tag], Vec::new()),
+        ))
+    };
+    let buff = timed_effect(
+        buff_tag,
+        Some(EffectPeriodTicks::new(ModifierMagnitude::Flat(4.0), false)),
+    );
+    assert!(apply_effect(&mut app, target, target, buff));
+    let buff_handle = active_effect_handles(&app, target)[0];
+    assert!(apply_effect(
+        &mut app,
+        target,
+        target,
+        timed_effect(stun_tag, None)
+    ));
+    let stun_handle = active_effect_handles(&app, target)[1];
+    run_effect_duration_tick(&mut app);
+    run_effect_period_tick(&mut app);
+
+    let period = app
+        .world()
+        .get::<bevy_tools::ActiveEffectPeriodTicks>(buff_handle)
+        .unwrap();
+    assert_eq!(period.get_current_tick(), 1);
+    assert_eq!(period.get_remain_ticks(), 3);
+    assert_eq!(period.get_progress(), 0.25);
+
+    let adjust_with_tags = |app: &mut App, tag: GameplayTag, adjustment| {
+        app.world_mut()
+            .run_system_once(move |mut params: bevy_tools::AbilitySystemParams| {
+                bevy_tools::adjust_active_effect_durations_with_tags(
+                    target,
+                    &[tag],
+                    adjustment,
+                    &mut params,
+                )
+            })
+            .unwrap()
+    };
+    assert_eq!(
+        adjust_with_tags(&mut app, buff_tag, EffectDurationAdjustment::Add(6)),
+        1
+    );
+    assert_eq!(
+        adjust_with_tags(&mut app, stun_tag, EffectDurationAdjustment::Scale(0.5)),
+        1
+    );
+    let remain_and_total = |app: &App, handle: Entity| {
+        let duration = app
+            .world()
+            .get::<ActiveEffectDurationTicks>(handle)
+            .unwrap();
+        (duration.get_remain_ticks(), duration.get_total_ticks())
+    };
+    assert_eq!(remain_and_total(&app, buff_handle), (15, 15));
+    assert_eq!(remain_and_total(&app, stun_handle), (5, 10));
+
+    let adjusted = app
+        .world_mut()
+        .run_system_once(move |mut params: bevy_tools::AbilitySystemParams| {
+            bevy_tools::adjust_active_effect_duration(
+                stun_handle,
+                EffectDurationAdjustment::Set(0),
+                &mut params,
+            )
+        })
+        .unwrap();
+    assert!(adjusted);
+    run_effect_duration_tick(&mut app);
+    app.update();
+    assert_eq!(active_effect_handles(&app, target), vec![buff_handle]);
+}
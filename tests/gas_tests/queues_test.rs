@@ -1,16 +1,20 @@
 use super::common_test::{
-    ability_task_count, current_value, instant_add_effect, register_attribute,
+    ability_task_count, add_modifier, add_tag_to_entity, apply_effect, attribute_set,
+    current_value, empty_effect_tags, instant_add_effect, register_attribute, register_tag,
     run_ability_activation_queue, run_ability_tasks, run_effect_application_queue,
-    set_ability_queue_limit, set_effect_queue_limit, spawn_ability_task, test_app,
+    set_ability_queue_limit, set_effect_queue_limit, spawn_ability_task, spawn_active_ability,
+    test_app,
 };
 use bevy::prelude::*;
 use bevy_tools::{
     AbilityActivationContext, AbilityActivationQueue, AbilityActivationStatus, AbilityChainContext,
     AbilitySpecHandle, AbilitySystemComponent, AbilityTask, AbilityTaskDef, AbilityTaskEvent,
-    AbilityTaskOnFinished, AbilityTaskOnFinishedDef, ActiveGameplayAbility, AttributeId,
-    EffectContext, EffectDurationTicks, EffectPayload, GameplayAbility, GameplayEffect,
-    GameplayEffectApplicationQueue, Modifier, ModifierMagnitude, ModifierMagnitudeCalculation,
-    ModifierOperation, StackingPolicy, UniqueName,
+    AbilityTaskOnFinished, AbilityTaskOnFinishedDef, ActiveGameplayAbility, AttributeClamp,
+    AttributeId, ConditionalGameplayEffect, EffectContext, EffectDurationTicks, EffectPayload,
+    GameplayAbility, GameplayEffect, GameplayEffectApplicationQueue, GameplayEffectChainError,
+    GameplayTagContainer, Modifier, ModifierMagnitude, ModifierMagnitudeCalculation,
+    ModifierOperation, SetByCallerMagnitude, StackingPolicy, TagRequirements, UniqueName,
+    UniqueNamePool,
 };
 use std::sync::Arc;
 
@@ -342,6 +346,58 @@ fn task_can_enqueue_gameplay_effect_application() {
     assert_eq!(current_value(&mut app, target, health), 15.0);
 }
 
+#[test]
+fn task_copies_set_by_caller_magnitudes_into_applied_effect_payload() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let charge = app
+        .world_mut()
+        .resource_mut::<UniqueNamePool>()
+        .new_name("ChargeDamage");
+    let source = app.world_mut().spawn_empty().id();
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(health, 100.0, AttributeClamp::None))
+        .id();
+    let active = spawn_active_ability(&mut app, source, target, AbilitySpecHandle::new(123));
+    let effect = Arc::new(GameplayEffect::new(
+        vec![Modifier::new(
+            health,
+            ModifierOperation::Add,
+            ModifierMagnitude::SetByCaller(SetByCallerMagnitude::new(charge)),
+        )],
+        EffectDurationTicks::Instant,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+    let task = spawn_ability_task(
+        &mut app,
+        AbilityTask::wait_ticks(
+            active,
+            2,
+            AbilityTaskOnFinished::ApplyGameplayEffect {
+                source,
+                target,
+                effect,
+                level: 1,
+            },
+        )
+        .with_set_by_caller_magnitude(charge, -10.0),
+    );
+
+    run_ability_tasks(&mut app);
+    // Charging for longer raises the damage the task applies when it finishes.
+    app.world_mut()
+        .get_mut::<AbilityTask>(task)
+        .unwrap()
+        .set_set_by_caller_magnitude(charge, -25.0);
+    run_ability_tasks(&mut app);
+    run_effect_application_queue(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 75.0);
+}
+
 #[test]
 fn task_effect_application_inherits_activation_context_payload() {
     let mut app = test_app();
@@ -490,3 +546,69 @@ fn task_emit_event_triggers_observer_with_full_payload() {
     assert_eq!(captured.event_id, Some(event_id));
     assert_eq!(captured.level, Some(9));
 }
+
+#[test]
+fn conditional_effect_is_queued_only_when_source_tags_pass() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let ignite = register_tag(&mut app, "Talent.Ignite");
+    let burn = instant_add_effect(health, -3.0);
+    let damage = Arc::new(
+        GameplayEffect::new(
+            vec![add_modifier(health, -10.0)],
+            EffectDurationTicks::Instant,
+            None,
+            1.0,
+            StackingPolicy::non_stacking(),
+            empty_effect_tags(),
+        )
+        .with_conditional_effect(ConditionalGameplayEffect::new(
+            burn,
+            TagRequirements::new(vec![ignite], Vec::new()),
+        )),
+    );
+    let source = app.world_mut().spawn(GameplayTagContainer::default()).id();
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(health, 100.0, AttributeClamp::None))
+        .id();
+
+    assert!(apply_effect(&mut app, target, source, damage.clone()));
+    assert!(
+        app.world()
+            .resource::<GameplayEffectApplicationQueue>()
+            .is_empty()
+    );
+    assert_eq!(current_value(&mut app, target, health), 90.0);
+
+    add_tag_to_entity(&mut app, source, ignite);
+    assert!(apply_effect(&mut app, target, source, damage));
+    assert_eq!(
+        app.world()
+            .resource::<GameplayEffectApplicationQueue>()
+            .len(),
+        1
+    );
+
+    run_effect_application_queue(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 77.0);
+}
+
+#[test]
+fn effect_chain_depth_is_limited() {
+    let mut payload = EffectPayload::new(Entity::PLACEHOLDER, None, 1);
+    for _ in 0..EffectPayload::MAX_EFFECT_CHAIN_DEPTH {
+        payload = payload.next_in_effect_chain().unwrap();
+    }
+
+    assert_eq!(
+        payload.get_effect_chain_depth(),
+        EffectPayload::MAX_EFFECT_CHAIN_DEPTH
+    );
+    assert_eq!(
+        payload.next_in_effect_chain().err(),
+        Some(GameplayEffectChainError::DepthExceeded {
+            max_depth: EffectPayload::MAX_EFFECT_CHAIN_DEPTH,
+        })
+    );
+}
@@ -1,17 +1,23 @@
 use super::common_test::{
-    active_effect_handles, add_tag_to_entity, apply_effect_with_payload, attribute_set,
-    current_value, empty_effect_tags, register_attribute, register_tag,
-    run_active_effect_index_reconcile, run_fixed_update, test_app,
+    active_effect_handles, add_modifier, add_tag_to_entity, apply_effect, apply_effect_result,
+    apply_effect_with_payload, attribute_set, current_value, empty_effect_tags, instant_add_effect,
+    register_attribute, register_tag, run_active_effect_index_reconcile, run_effect_period_tick,
+    run_fixed_update, test_app,
 };
 use bevy::prelude::*;
 use bevy_tools::{
     AbilityActivationContext, AbilityActivationQueue, AbilitySystemComponent, AbilityTaskDef,
-    AbilityTaskOnFinishedDef, AttributeClamp, AttributeId, AttributeSet, EffectContext,
-    EffectDurationTicks, EffectPayload, GameplayAbility, GameplayEffect,
-    GameplayEffectApplicationQueue, GameplayTag, GameplayTagContainer, Modifier, ModifierMagnitude,
-    ModifierMagnitudeCalculation, ModifierOperation, StackingPolicy,
+    AbilityTaskOnFinishedDef, ActiveEffectDurationTicks, AttributeBasedMagnitude,
+    AttributeCaptureSide, AttributeCaptureValue, AttributeClamp, AttributeId, AttributeSet,
+    EffectApplicationError, EffectContext, EffectDurationTicks, EffectPayload, EffectPeriodTicks,
+    GameplayAbility, GameplayAbilitySystemState, GameplayEffect, GameplayEffectApplicationQueue,
+    GameplayEffectExecutedEvent, GameplayEffectExecutionCalculation, GameplayEffectExecutionOutput,
+    GameplayEffectExecutionParams, GameplayTag, GameplayTagContainer, GameplayTimeScale, Modifier,
+    ModifierMagnitude, ModifierMagnitudeCalculation, ModifierOperation, ModifierSpec,
+    StackingPolicy, StackingType,
 };
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 struct LevelMagnitude {
     scale: f64,
@@ -53,6 +59,48 @@ impl ModifierMagnitudeCalculation for SourceTagMagnitude {
     }
 }
 
+struct ShieldedDamageExecution {
+    attack: AttributeId,
+    crit_chance: AttributeId,
+    armor: AttributeId,
+    shield: AttributeId,
+    health: AttributeId,
+    critical: GameplayTag,
+}
+
+impl GameplayEffectExecutionCalculation for ShieldedDamageExecution {
+    fn execute(&self, params: &mut GameplayEffectExecutionParams) -> GameplayEffectExecutionOutput {
+        let capture = |attribute, side| {
+            params
+                .capture_attribute(attribute, side, false, AttributeCaptureValue::Current)
+                .unwrap_or(0.0)
+        };
+        let attack = capture(self.attack, AttributeCaptureSide::Source);
+        let crit_chance = capture(self.crit_chance, AttributeCaptureSide::Source);
+        let armor = capture(self.armor, AttributeCaptureSide::Target);
+        let shield = capture(self.shield, AttributeCaptureSide::Target);
+
+        let mut output = GameplayEffectExecutionOutput::new();
+        let mut damage = (attack - armor).max(0.0);
+        if params.get_random().random_bool(crit_chance) {
+            damage *= 2.0;
+            output.add_tag(self.critical);
+        }
+        let absorbed = damage.min(shield);
+        output
+            .with_modifier(ModifierSpec::new(
+                self.shield,
+                ModifierOperation::Add,
+                -absorbed,
+            ))
+            .with_modifier(ModifierSpec::new(
+                self.health,
+                ModifierOperation::Add,
+                absorbed - damage,
+            ))
+    }
+}
+
 #[test]
 fn fixed_update_processes_queued_effect_before_next_duration_tick() {
     let mut app = test_app();
@@ -83,6 +131,108 @@ fn fixed_update_processes_queued_effect_before_next_duration_tick() {
     assert!(active_effect_handles(&app, target).is_empty());
 }
 
+fn queue_two_tick_buff(app: &mut App, target: Entity, health: AttributeId) {
+    let effect = Arc::new(GameplayEffect::new(
+        vec![add_modifier(health, 5.0)],
+        EffectDurationTicks::DurationTicks(ModifierMagnitude::Flat(2.0)),
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+    app.world_mut()
+        .resource_mut::<GameplayEffectApplicationQueue>()
+        .push_application(target, effect, EffectPayload::new(target, None, 1));
+}
+
+fn remain_ticks(app: &App, target: Entity) -> Option<u32> {
+    let handle = *active_effect_handles(app, target).first()?;
+    app.world()
+        .entity(handle)
+        .get::<ActiveEffectDurationTicks>()
+        .map(ActiveEffectDurationTicks::get_remain_ticks)
+}
+
+#[test]
+fn time_scale_slows_and_pauses_effects_of_one_entity() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let normal = app
+        .world_mut()
+        .spawn(attribute_set(health, 10.0, AttributeClamp::None))
+        .id();
+    let slowed = app
+        .world_mut()
+        .spawn((
+            attribute_set(health, 10.0, AttributeClamp::None),
+            GameplayTimeScale::new(0.5),
+        ))
+        .id();
+    queue_two_tick_buff(&mut app, normal, health);
+    queue_two_tick_buff(&mut app, slowed, health);
+
+    // The applications are processed after this update's effect ticks.
+    run_fixed_update(&mut app);
+    run_fixed_update(&mut app);
+    // Half speed: the slowed entity advances on every second fixed tick.
+    assert_eq!(remain_ticks(&app, normal), Some(1));
+    assert_eq!(remain_ticks(&app, slowed), Some(1));
+
+    run_fixed_update(&mut app);
+    assert_eq!(remain_ticks(&app, normal), None);
+    assert_eq!(current_value(&mut app, normal, health), 10.0);
+    assert_eq!(remain_ticks(&app, slowed), Some(1));
+
+    app.world_mut()
+        .get_mut::<GameplayTimeScale>(slowed)
+        .unwrap()
+        .set_paused(true);
+    for _ in 0..4 {
+        run_fixed_update(&mut app);
+    }
+    assert_eq!(remain_ticks(&app, slowed), Some(1));
+    assert_eq!(current_value(&mut app, slowed, health), 15.0);
+
+    let mut time_scale = app
+        .world_mut()
+        .get_mut::<GameplayTimeScale>(slowed)
+        .unwrap();
+    time_scale.set_paused(false);
+    time_scale.set_scale(2.0);
+    run_fixed_update(&mut app);
+    assert_eq!(remain_ticks(&app, slowed), None);
+    assert_eq!(current_value(&mut app, slowed, health), 10.0);
+}
+
+#[test]
+fn paused_state_freezes_effect_ticks_but_keeps_processing_queues() {
+    let mut app = test_app();
+    let health = register_attribute(&mut app, "Health");
+    let target = app
+        .world_mut()
+        .spawn(attribute_set(health, 10.0, AttributeClamp::None))
+        .id();
+    let set_state = |app: &mut App, state: GameplayAbilitySystemState| {
+        app.world_mut()
+            .resource_mut::<NextState<GameplayAbilitySystemState>>()
+            .set(state);
+        app.world_mut().run_schedule(StateTransition);
+    };
+
+    set_state(&mut app, GameplayAbilitySystemState::Paused);
+    queue_two_tick_buff(&mut app, target, health);
+    for _ in 0..3 {
+        run_fixed_update(&mut app);
+    }
+    assert_eq!(remain_ticks(&app, target), Some(2));
+    assert_eq!(current_value(&mut app, target, health), 15.0);
+
+    set_state(&mut app, GameplayAbilitySystemState::Running);
+    run_fixed_update(&mut app);
+    run_fixed_update(&mut app);
+    assert_eq!(remain_ticks(&app, target), None);
+}
+
 #[test]
 fn fixed_update_activation_tasks_and_cleanup_run_in_plugin_order() {
     let mut app = test_app();
@@ -263,3 +413,253 @@ fn reconcile_removes_externally_despawned_active_effect_from_target_index() {
     run_active_effect_index_reconcile(&mut app);
     assert!(active_effect_handles(&app, target).is_empty());
 }
+
+#[test]
+fn attribute_based_magnitude_reads_snapshot_live_and_bonus_values() {
+    let mut app = test_app();
+    let power = register_attribute(&mut app, "Power");
+    let armor = register_attribute(&mut app, "Armor");
+    let damage = register_attribute(&mut app, "Damage");
+    let mut source_attributes = attribute_set(power, 10.0, AttributeClamp::None);
+    let snapshot = source_attributes.make_snapshot(Entity::PLACEHOLDER);
+    let source = app.world_mut().spawn(source_attributes).id();
+    let mut target_attributes = attribute_set(armor, 4.0, AttributeClamp::None);
+    target_attributes.initialize_attribute(damage, 0.0, None, AttributeClamp::None);
+    let target = app.world_mut().spawn(target_attributes).id();
+    let power_buff = Arc::new(GameplayEffect::new(
+        vec![add_modifier(power, 5.0)],
+        EffectDurationTicks::Infinite,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+    assert!(apply_effect(&mut app, source, source, power_buff));
+
+    let attribute_based = |attribute, side, snapshot, value| {
+        ModifierMagnitude::AttributeBased(AttributeBasedMagnitude::new(
+            attribute, side, snapshot, value,
+        ))
+    };
+    let effect = Arc::new(GameplayEffect::new(
+        vec![
+            Modifier::new(
+                damage,
+                ModifierOperation::Add,
+                attribute_based(
+                    power,
+                    AttributeCaptureSide::Source,
+                    true,
+                    AttributeCaptureValue::Current,
+                ),
+            ),
+            Modifier::new(
+                damage,
+                ModifierOperation::Add,
+                attribute_based(
+                    power,
+                    AttributeCaptureSide::Source,
+                    false,
+                    AttributeCaptureValue::Bonus,
+                ),
+            ),
+            Modifier::new(
+                damage,
+                ModifierOperation::Add,
+                ModifierMagnitude::AttributeBased(
+                    AttributeBasedMagnitude::new(
+                        armor,
+                        AttributeCaptureSide::Target,
+                        false,
+                        AttributeCaptureValue::Base,
+                    )
+                    .with_coefficient(-2.0)
+                    .with_pre_multiply_additive(1.0)
+                    .with_post_multiply_additive(3.0),
+                ),
+            ),
+        ],
+        EffectDurationTicks::Instant,
+        None,
+        1.0,
+        StackingPolicy::non_stacking(),
+        empty_effect_tags(),
+    ));
+
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        effect,
+        EffectPayload::new(source, None, 1).with_source_snapshot(snapshot),
+    ));
+    // snapshot current 10 + live bonus 5 + (-2 * (4 + 1) + 3)
+    assert_eq!(current_value(&mut app, target, damage), 8.0);
+}
+
+#[derive(Resource, Default)]
+struct ExecutedOutputs(Vec<GameplayEffectExecutionOutput>);
+
+#[test]
+fn execution_outputs_modify_several_attributes_and_report_tags() {
+    let mut app = test_app();
+    let attack = register_attribute(&mut app, "Attack");
+    let crit_chance = register_attribute(&mut app, "CritChance");
+    let armor = register_attribute(&mut app, "Armor");
+    let shield = register_attribute(&mut app, "Shield");
+    let health = register_attribute(&mut app, "Health");
+    let critical = register_tag(&mut app, "Result.Critical");
+    app.init_resource::<ExecutedOutputs>().add_observer(
+        |event: On<GameplayEffectExecutedEvent>, mut outputs: ResMut<ExecutedOutputs>| {
+            outputs.0.push(event.get_output().clone());
+        },
+    );
+
+    let mut source_attrs = attribute_set(attack, 30.0, AttributeClamp::None);
+    source_attrs.initialize_attribute(crit_chance, 1.0, None, AttributeClamp::None);
+    let source = app.world_mut().spawn(source_attrs).id();
+    let mut target_attrs = attribute_set(armor, 10.0, AttributeClamp::None);
+    target_attrs.initialize_attribute(shield, 15.0, None, AttributeClamp::None);
+    target_attrs.initialize_attribute(health, 100.0, None, AttributeClamp::None);
+    let target = app.world_mut().spawn(target_attrs).id();
+
+    let effect = Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::Instant,
+            None,
+            1.0,
+            StackingPolicy::non_stacking(),
+            empty_effect_tags(),
+        )
+        .with_execution(ShieldedDamageExecution {
+            attack,
+            crit_chance,
+            armor,
+            shield,
+            health,
+            critical,
+        }),
+    );
+
+    assert!(apply_effect_with_payload(
+        &mut app,
+        target,
+        effect,
+        EffectPayload::new(source, None, 1),
+    ));
+    // (30 - 10) * 2 = 40 damage, 15 absorbed by the shield.
+    assert_eq!(current_value(&mut app, target, shield), 0.0);
+    assert_eq!(current_value(&mut app, target, health), 75.0);
+
+    let outputs = &app.world().resource::<ExecutedOutputs>().0;
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].get_tags(), &[critical]);
+    assert_eq!(outputs[0].get_modifiers().len(), 2);
+}
+
+struct ArmorDamageExecution {
+    armor: AttributeId,
+    health: AttributeId,
+    last_stack_count: Arc<AtomicU32>,
+}
+
+impl GameplayEffectExecutionCalculation for ArmorDamageExecution {
+    fn execute(&self, params: &mut GameplayEffectExecutionParams) -> GameplayEffectExecutionOutput {
+        self.last_stack_count
+            .store(params.get_stack_count(), Ordering::Relaxed);
+        let armor = params
+            .capture_attribute(
+                self.armor,
+                AttributeCaptureSide::Target,
+                false,
+                AttributeCaptureValue::Current,
+            )
+            .unwrap_or(0.0);
+        GameplayEffectExecutionOutput::new().with_modifier(ModifierSpec::new(
+            self.health,
+            ModifierOperation::Add,
+            -armor,
+        ))
+    }
+}
+
+#[test]
+fn periodic_executions_run_on_every_period_with_current_target_and_stack() {
+    let mut app = test_app();
+    let armor = register_attribute(&mut app, "Armor");
+    let health = register_attribute(&mut app, "Health");
+    app.init_resource::<ExecutedOutputs>().add_observer(
+        |event: On<GameplayEffectExecutedEvent>, mut outputs: ResMut<ExecutedOutputs>| {
+            outputs.0.push(event.get_output().clone());
+        },
+    );
+
+    let mut target_attrs = attribute_set(armor, 5.0, AttributeClamp::None);
+    target_attrs.initialize_attribute(health, 100.0, None, AttributeClamp::None);
+    let target = app.world_mut().spawn(target_attrs).id();
+    let source = app.world_mut().spawn_empty().id();
+
+    let last_stack_count = Arc::new(AtomicU32::new(0));
+    let execution = || ArmorDamageExecution {
+        armor,
+        health,
+        last_stack_count: last_stack_count.clone(),
+    };
+    let effect = Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::Infinite,
+            Some(EffectPeriodTicks::new(ModifierMagnitude::Flat(1.0), false)),
+            1.0,
+            StackingPolicy::linear_refreshing(StackingType::AggregateByTarget, 5),
+            empty_effect_tags(),
+        )
+        .with_execution(execution()),
+    );
+
+    assert!(apply_effect(&mut app, target, source, effect.clone()));
+    assert!(apply_effect(&mut app, target, source, effect));
+    assert!(app.world().resource::<ExecutedOutputs>().0.is_empty());
+
+    assert!(apply_effect(
+        &mut app,
+        target,
+        source,
+        instant_add_effect(armor, 5.0)
+    ));
+    run_effect_period_tick(&mut app);
+    // Live armor 10, scaled by two stacks.
+    assert_eq!(current_value(&mut app, target, health), 80.0);
+    assert_eq!(last_stack_count.load(Ordering::Relaxed), 2);
+
+    assert!(apply_effect(
+        &mut app,
+        target,
+        source,
+        instant_add_effect(armor, -5.0)
+    ));
+    run_effect_period_tick(&mut app);
+    assert_eq!(current_value(&mut app, target, health), 70.0);
+    assert_eq!(app.world().resource::<ExecutedOutputs>().0.len(), 2);
+
+    let lingering = Arc::new(
+        GameplayEffect::new(
+            Vec::new(),
+            EffectDurationTicks::Infinite,
+            None,
+            1.0,
+            StackingPolicy::non_stacking(),
+            empty_effect_tags(),
+        )
+        .with_execution(execution()),
+    );
+    assert!(matches!(
+        apply_effect_result(
+            &mut app,
+            target,
+            lingering,
+            EffectPayload::new(source, None, 1)
+        ),
+        Err(EffectApplicationError::InvalidSpec { .. })
+    ));
+}